@@ -1,4 +1,5 @@
 use rosu_memory_lib::init_loop;
+use rosu_memory_lib::reader::beatmap::common::{BeatmapDifficultySettings, DEFAULT_PP_ACCURACIES};
 use rosu_memory_lib::reader::beatmap::BeatmapReader;
 use rosu_memory_lib::reader::common::OsuClientKind;
 use rosu_memory_lib::Error;
@@ -110,6 +111,13 @@ fn main() -> Result<(), Error> {
         Ok(star_rating) => println!("Current beatmap star rating: {star_rating:?}"),
         Err(e) => println!("Error: {e:?}"),
     }
+    match beatmap_reader.performance(
+        &BeatmapDifficultySettings::with_mods(64),
+        &DEFAULT_PP_ACCURACIES,
+    ) {
+        Ok(performance) => println!("Current beatmap DT performance: {performance:?}"),
+        Err(e) => println!("Error: {e:?}"),
+    }
     match beatmap_reader.info() {
         Ok(info) => println!("Current beatmap info: {info:?}"),
         Err(e) => println!("Error: {e:?}"),
@@ -0,0 +1,25 @@
+use rosu_memory_lib::db::osu;
+use rosu_memory_lib::init_loop;
+use rosu_memory_lib::reader::beatmap::BeatmapReader;
+use rosu_memory_lib::reader::common::OsuClientKind;
+use rosu_memory_lib::Error;
+
+fn main() -> Result<(), Error> {
+    let (mut state, process) = init_loop(500)?;
+    let db = osu::read(&process, &mut state)?;
+    println!(
+        "osu!.db version {} with {} beatmaps",
+        db.version,
+        db.beatmaps.len()
+    );
+
+    let mut beatmap_reader = BeatmapReader::new(&process, &mut state, OsuClientKind::Stable)?;
+    match beatmap_reader.md5() {
+        Ok(md5) => match db.find_by_md5(&md5) {
+            Some(beatmap) => println!("Current beatmap in osu!.db: {beatmap:?}"),
+            None => println!("Current beatmap is not in osu!.db"),
+        },
+        Err(e) => println!("Error: {e:?}"),
+    }
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use crate::Error;
+
+/// Reader for the primitive types used by osu! database and replay files
+///
+/// Everything is little-endian, strings are prefixed by `0x0b` + ULEB128 length
+/// or are a single `0x00` byte when absent
+pub struct BinaryReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BinaryReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if self.remaining() < len {
+            return Err(Error::Parse(format!(
+                "Unexpected end of data at {} (wanted {len} bytes, {} left)",
+                self.pos,
+                self.remaining()
+            )));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Reads an `i32` item count, negative counts are 0
+    ///
+    /// Fails when the items can't fit in what is left with at least
+    /// `min_item_size` bytes each, so a corrupt count can't allocate too much
+    pub fn read_count(&mut self, min_item_size: usize) -> Result<usize, Error> {
+        let count = self.read_i32()?.max(0) as usize;
+        if count.saturating_mul(min_item_size.max(1)) > self.remaining() {
+            return Err(Error::Parse(format!(
+                "Invalid count {count} at {} ({} bytes left)",
+                self.pos,
+                self.remaining()
+            )));
+        }
+        Ok(count)
+    }
+
+    pub fn skip(&mut self, len: usize) -> Result<(), Error> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        // Safety: unwrap here because read_bytes returns exactly N bytes
+        Ok(self.read_bytes(N)?.try_into().unwrap())
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    pub fn read_bool(&mut self) -> Result<bool, Error> {
+        Ok(self.read_u8()? != 0)
+    }
+
+    pub fn read_i16(&mut self) -> Result<i16, Error> {
+        Ok(i16::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, Error> {
+        Ok(u16::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_i32(&mut self) -> Result<i32, Error> {
+        Ok(i32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_i64(&mut self) -> Result<i64, Error> {
+        Ok(i64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_f32(&mut self) -> Result<f32, Error> {
+        Ok(f32::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_f64(&mut self) -> Result<f64, Error> {
+        Ok(f64::from_le_bytes(self.read_array()?))
+    }
+
+    pub fn read_uleb128(&mut self) -> Result<u64, Error> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let byte = self.read_u8()?;
+            if shift >= 64 {
+                return Err(Error::Parse("ULEB128 value too large".to_string()));
+            }
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+            shift += 7;
+        }
+    }
+
+    pub fn read_optional_string(&mut self) -> Result<Option<String>, Error> {
+        match self.read_u8()? {
+            0x00 => Ok(None),
+            0x0b => {
+                let len = self.read_uleb128()? as usize;
+                let bytes = self.read_bytes(len)?;
+                String::from_utf8(bytes.to_vec())
+                    .map(Some)
+                    .map_err(|e| Error::Parse(format!("Invalid UTF-8 string: {e}")))
+            }
+            b => Err(Error::Parse(format!(
+                "Invalid string marker {b:#x} at {}",
+                self.pos - 1
+            ))),
+        }
+    }
+
+    /// Absent strings are returned as empty strings
+    pub fn read_string(&mut self) -> Result<String, Error> {
+        Ok(self.read_optional_string()?.unwrap_or_default())
+    }
+}
+
+/// Writer counterpart of [`BinaryReader`]
+#[derive(Default)]
+pub struct BinaryWriter {
+    buf: Vec<u8>,
+}
+
+impl BinaryWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.buf
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.buf.push(value);
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_u8(value as u8);
+    }
+
+    pub fn write_i16(&mut self, value: i16) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_u16(&mut self, value: u16) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_i32(&mut self, value: i32) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_i64(&mut self, value: i64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_f32(&mut self, value: f32) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_f64(&mut self, value: f64) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_uleb128(&mut self, mut value: u64) {
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.write_u8(byte);
+                return;
+            }
+            self.write_u8(byte | 0x80);
+        }
+    }
+
+    pub fn write_optional_string(&mut self, value: Option<&str>) {
+        match value {
+            None => self.write_u8(0x00),
+            Some(s) => {
+                self.write_u8(0x0b);
+                self.write_uleb128(s.len() as u64);
+                self.write_bytes(s.as_bytes());
+            }
+        }
+    }
+
+    pub fn write_string(&mut self, value: &str) {
+        self.write_optional_string(Some(value));
+    }
+}
+
+/// .NET `DateTime` ticks (100ns intervals since 0001-01-01) at the unix epoch
+pub const UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+
+/// Converts .NET `DateTime` ticks as stored by osu! to unix milliseconds
+pub fn ticks_to_unix_millis(ticks: i64) -> i64 {
+    (ticks - UNIX_EPOCH_TICKS) / 10_000
+}
+
+/// Converts unix milliseconds to .NET `DateTime` ticks as stored by osu!
+pub fn unix_millis_to_ticks(millis: i64) -> i64 {
+    millis * 10_000 + UNIX_EPOCH_TICKS
+}
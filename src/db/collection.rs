@@ -0,0 +1,157 @@
+use std::path::{Path, PathBuf};
+
+use crate::db::binary::{BinaryReader, BinaryWriter};
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Collection {
+    /// `None` when the name is written as an absent string, which is kept
+    /// apart from an empty name so the file is written back unchanged
+    pub name: Option<String>,
+    /// md5 hashes of the beatmaps in the collection, `None` for absent ones
+    pub beatmaps: Vec<Option<String>>,
+}
+
+impl Collection {
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or_default()
+    }
+
+    pub fn contains(&self, md5: &str) -> bool {
+        self.beatmaps.iter().any(|b| b.as_deref() == Some(md5))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CollectionDb {
+    pub version: i32,
+    pub collections: Vec<Collection>,
+}
+
+impl CollectionDb {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = BinaryReader::new(bytes);
+        let version = r.read_i32()?;
+        // name and beatmap count
+        let count = r.read_count(5)?;
+        let mut collections = Vec::with_capacity(count);
+        for _ in 0..count {
+            let name = r.read_optional_string()?;
+            let beatmap_count = r.read_count(1)?;
+            let mut beatmaps = Vec::with_capacity(beatmap_count);
+            for _ in 0..beatmap_count {
+                beatmaps.push(r.read_optional_string()?);
+            }
+            collections.push(Collection { name, beatmaps });
+        }
+        Ok(Self {
+            version,
+            collections,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = BinaryWriter::new();
+        w.write_i32(self.version);
+        w.write_i32(self.collections.len() as i32);
+        for collection in &self.collections {
+            w.write_optional_string(collection.name.as_deref());
+            w.write_i32(collection.beatmaps.len() as i32);
+            for md5 in &collection.beatmaps {
+                w.write_optional_string(md5.as_deref());
+            }
+        }
+        w.into_inner()
+    }
+
+    /// osu! only reads `collection.db` on startup and overwrites it on exit,
+    /// so changes made while it is running will be lost
+    pub fn write_to_path(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        Ok(std::fs::write(path, self.to_bytes())?)
+    }
+
+    /// Unnamed collections are found with an empty name
+    pub fn get(&self, name: &str) -> Option<&Collection> {
+        self.collections.iter().find(|c| c.name() == name)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Collection> {
+        self.collections.iter_mut().find(|c| c.name() == name)
+    }
+
+    /// Collections containing the given beatmap
+    pub fn containing<'a>(&'a self, md5: &'a str) -> impl Iterator<Item = &'a Collection> + 'a {
+        self.collections.iter().filter(move |c| c.contains(md5))
+    }
+
+    /// Adds a beatmap to a collection, creating the collection if needed
+    ///
+    /// Returns `false` if the beatmap was already in the collection
+    pub fn add(&mut self, name: &str, md5: &str) -> bool {
+        let collection = match self.collections.iter().position(|c| c.name() == name) {
+            Some(idx) => &mut self.collections[idx],
+            None => {
+                self.collections.push(Collection {
+                    name: Some(name.to_string()),
+                    beatmaps: Vec::new(),
+                });
+                self.collections.last_mut().unwrap()
+            }
+        };
+        if collection.contains(md5) {
+            return false;
+        }
+        collection.beatmaps.push(Some(md5.to_string()));
+        true
+    }
+
+    /// Returns `false` if the beatmap was not in the collection
+    pub fn remove(&mut self, name: &str, md5: &str) -> bool {
+        match self.get_mut(name) {
+            Some(collection) => {
+                let len = collection.beatmaps.len();
+                collection.beatmaps.retain(|b| b.as_deref() != Some(md5));
+                collection.beatmaps.len() != len
+            }
+            None => false,
+        }
+    }
+}
+
+/// Path to `collection.db` of the running osu! instance
+pub fn path(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
+    Ok(crate::db::osu_dir(p, state)?.join("collection.db"))
+}
+
+/// Reads `collection.db` of the running osu! instance
+pub fn read(p: &Process, state: &mut State) -> Result<CollectionDb, Error> {
+    CollectionDb::from_path(path(p, state)?)
+}
+
+/// Collections containing the currently selected beatmap
+pub fn current_beatmap_collections<'a>(
+    p: &Process,
+    state: &mut State,
+    db: &'a CollectionDb,
+) -> Result<Vec<&'a Collection>, Error> {
+    let md5 = crate::reader::beatmap::stable::memory::md5(p, state)?;
+    Ok(db.collections.iter().filter(|c| c.contains(&md5)).collect())
+}
+
+/// Adds the currently selected beatmap to the named collection,
+/// see [`CollectionDb::add`]
+pub fn add_current_beatmap(
+    p: &Process,
+    state: &mut State,
+    db: &mut CollectionDb,
+    name: &str,
+) -> Result<bool, Error> {
+    let md5 = crate::reader::beatmap::stable::memory::md5(p, state)?;
+    Ok(db.add(name, &md5))
+}
@@ -0,0 +1,26 @@
+pub mod binary;
+pub mod collection;
+pub mod osu;
+pub mod scores;
+
+use std::path::PathBuf;
+
+use crate::reader::common::stable::memory::path_folder;
+use crate::reader::structs::State;
+use crate::reader::wine;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Returns the osu! installation folder where the `.db` files live
+///
+/// Uses the executable folder when known, else the parent of the `Songs` folder
+pub fn osu_dir(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
+    if let Some(executable_dir) = wine::executable_dir(p, state) {
+        return Ok(executable_dir);
+    }
+    let songs = path_folder(p, state)?;
+    songs
+        .parent()
+        .map(|p| p.to_path_buf())
+        .ok_or_else(|| Error::NotAvailable("Cannot find osu! folder".to_string()))
+}
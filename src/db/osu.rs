@@ -0,0 +1,372 @@
+use std::path::{Path, PathBuf};
+
+use crate::db::binary::BinaryReader;
+use crate::reader::beatmap::common::BeatmapStatus;
+use crate::reader::common::GameMode;
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// First version storing AR/CS/HP/OD as floats and caching star ratings
+pub const VERSION_FLOAT_DIFFICULTY: i32 = 20140609;
+/// First version without the size of each beatmap entry
+pub const VERSION_NO_ENTRY_SIZE: i32 = 20191106;
+/// First version storing cached star ratings as floats instead of doubles
+pub const VERSION_FLOAT_STAR_RATING: i32 = 20250107;
+
+/// Less than the smallest beatmap entry of any version, empty strings included
+const BEATMAP_MIN_SIZE: usize = 64;
+
+#[derive(Debug, Clone)]
+pub struct OsuDb {
+    pub version: i32,
+    pub folder_count: i32,
+    pub account_unlocked: bool,
+    /// .NET ticks, see `db::binary::ticks_to_unix_millis`
+    pub account_unlock_date: i64,
+    pub player_name: String,
+    pub beatmaps: Vec<OsuDbBeatmap>,
+    pub user_permissions: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OsuDbStarRating {
+    pub mods: u32,
+    pub stars: f64,
+}
+
+/// Star ratings cached by osu! for each mode, only nomod and rate/difficulty
+/// changing mod combinations are cached
+#[derive(Debug, Clone, Default)]
+pub struct OsuDbStarRatings {
+    pub osu: Vec<OsuDbStarRating>,
+    pub taiko: Vec<OsuDbStarRating>,
+    pub catch: Vec<OsuDbStarRating>,
+    pub mania: Vec<OsuDbStarRating>,
+}
+
+impl OsuDbStarRatings {
+    pub fn for_mode(&self, mode: GameMode) -> &[OsuDbStarRating] {
+        match mode {
+            GameMode::Osu => &self.osu,
+            GameMode::Taiko => &self.taiko,
+            GameMode::Catch => &self.catch,
+            GameMode::Mania => &self.mania,
+            GameMode::Unknown => &[],
+        }
+    }
+
+    pub fn stars(&self, mode: GameMode, mods: u32) -> Option<f64> {
+        self.for_mode(mode)
+            .iter()
+            .find(|s| s.mods == mods)
+            .map(|s| s.stars)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OsuDbTimingPoint {
+    pub bpm: f64,
+    pub offset: f64,
+    pub uninherited: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct OsuDbBeatmap {
+    pub artist: String,
+    pub artist_unicode: String,
+    pub title: String,
+    pub title_unicode: String,
+    pub creator: String,
+    pub difficulty: String,
+    pub audio_file: String,
+    pub md5: String,
+    pub filename: String,
+    pub ranked_status: BeatmapStatus,
+    pub circle_count: i16,
+    pub slider_count: i16,
+    pub spinner_count: i16,
+    /// .NET ticks
+    pub last_modification_time: i64,
+    pub ar: f32,
+    pub cs: f32,
+    pub hp: f32,
+    pub od: f32,
+    pub slider_velocity: f64,
+    pub star_ratings: OsuDbStarRatings,
+    /// in seconds
+    pub drain_time: i32,
+    /// in milliseconds
+    pub total_time: i32,
+    pub preview_time: i32,
+    pub timing_points: Vec<OsuDbTimingPoint>,
+    pub id: i32,
+    pub set_id: i32,
+    pub thread_id: i32,
+    pub grade_osu: u8,
+    pub grade_taiko: u8,
+    pub grade_catch: u8,
+    pub grade_mania: u8,
+    pub local_offset: i16,
+    pub stack_leniency: f32,
+    pub mode: GameMode,
+    pub source: String,
+    pub tags: String,
+    pub online_offset: i16,
+    pub title_font: String,
+    pub unplayed: bool,
+    /// .NET ticks
+    pub last_played: i64,
+    pub is_osz2: bool,
+    pub folder: String,
+    /// .NET ticks
+    pub last_online_check: i64,
+    pub ignore_beatmap_sound: bool,
+    pub ignore_beatmap_skin: bool,
+    pub disable_storyboard: bool,
+    pub disable_video: bool,
+    pub visual_override: bool,
+    pub mania_scroll_speed: u8,
+}
+
+impl OsuDbBeatmap {
+    /// Path of the .osu file relative to the `Songs` folder
+    pub fn relative_path(&self) -> PathBuf {
+        PathBuf::from(&self.folder).join(&self.filename)
+    }
+
+    fn matches(&self, query: &str) -> bool {
+        [
+            &self.artist,
+            &self.artist_unicode,
+            &self.title,
+            &self.title_unicode,
+            &self.creator,
+            &self.difficulty,
+            &self.source,
+            &self.tags,
+        ]
+        .iter()
+        .any(|field| field.to_lowercase().contains(query))
+    }
+}
+
+impl OsuDb {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = BinaryReader::new(bytes);
+        let version = r.read_i32()?;
+        let folder_count = r.read_i32()?;
+        let account_unlocked = r.read_bool()?;
+        let account_unlock_date = r.read_i64()?;
+        let player_name = r.read_string()?;
+        let count = r.read_count(BEATMAP_MIN_SIZE)?;
+
+        let mut beatmaps = Vec::with_capacity(count);
+        for _ in 0..count {
+            beatmaps.push(read_beatmap(&mut r, version)?);
+        }
+
+        // not written by some old versions
+        let user_permissions = if r.remaining() >= 4 { r.read_i32()? } else { 0 };
+
+        Ok(Self {
+            version,
+            folder_count,
+            account_unlocked,
+            account_unlock_date,
+            player_name,
+            beatmaps,
+            user_permissions,
+        })
+    }
+
+    pub fn find_by_md5(&self, md5: &str) -> Option<&OsuDbBeatmap> {
+        self.beatmaps.iter().find(|b| b.md5 == md5)
+    }
+
+    pub fn find_by_id(&self, id: i32) -> Option<&OsuDbBeatmap> {
+        self.beatmaps.iter().find(|b| b.id == id)
+    }
+
+    /// Case insensitive search on artist, title, creator, difficulty, source and tags
+    pub fn search<'a>(&'a self, query: &str) -> impl Iterator<Item = &'a OsuDbBeatmap> + 'a {
+        let query = query.to_lowercase();
+        self.beatmaps.iter().filter(move |b| b.matches(&query))
+    }
+}
+
+fn read_star_ratings(r: &mut BinaryReader, version: i32) -> Result<Vec<OsuDbStarRating>, Error> {
+    // type code, mods, type code and at least a float
+    let count = r.read_count(10)?;
+    let mut ratings = Vec::with_capacity(count);
+    for _ in 0..count {
+        // each value is prefixed by its .NET type code
+        r.skip(1)?;
+        let mods = r.read_i32()? as u32;
+        r.skip(1)?;
+        let stars = if version >= VERSION_FLOAT_STAR_RATING {
+            r.read_f32()? as f64
+        } else {
+            r.read_f64()?
+        };
+        ratings.push(OsuDbStarRating { mods, stars });
+    }
+    Ok(ratings)
+}
+
+fn read_difficulty_value(r: &mut BinaryReader, version: i32) -> Result<f32, Error> {
+    if version >= VERSION_FLOAT_DIFFICULTY {
+        r.read_f32()
+    } else {
+        Ok(r.read_u8()? as f32)
+    }
+}
+
+fn read_beatmap(r: &mut BinaryReader, version: i32) -> Result<OsuDbBeatmap, Error> {
+    if version < VERSION_NO_ENTRY_SIZE {
+        r.read_i32()?;
+    }
+
+    let artist = r.read_string()?;
+    let artist_unicode = r.read_string()?;
+    let title = r.read_string()?;
+    let title_unicode = r.read_string()?;
+    let creator = r.read_string()?;
+    let difficulty = r.read_string()?;
+    let audio_file = r.read_string()?;
+    let md5 = r.read_string()?;
+    let filename = r.read_string()?;
+    let ranked_status = BeatmapStatus::from(r.read_u8()? as i16);
+    let circle_count = r.read_i16()?;
+    let slider_count = r.read_i16()?;
+    let spinner_count = r.read_i16()?;
+    let last_modification_time = r.read_i64()?;
+    let ar = read_difficulty_value(r, version)?;
+    let cs = read_difficulty_value(r, version)?;
+    let hp = read_difficulty_value(r, version)?;
+    let od = read_difficulty_value(r, version)?;
+    let slider_velocity = r.read_f64()?;
+
+    let star_ratings = if version >= VERSION_FLOAT_DIFFICULTY {
+        OsuDbStarRatings {
+            osu: read_star_ratings(r, version)?,
+            taiko: read_star_ratings(r, version)?,
+            catch: read_star_ratings(r, version)?,
+            mania: read_star_ratings(r, version)?,
+        }
+    } else {
+        OsuDbStarRatings::default()
+    };
+
+    let drain_time = r.read_i32()?;
+    let total_time = r.read_i32()?;
+    let preview_time = r.read_i32()?;
+
+    // bpm, offset and uninherited
+    let timing_point_count = r.read_count(17)?;
+    let mut timing_points = Vec::with_capacity(timing_point_count);
+    for _ in 0..timing_point_count {
+        timing_points.push(OsuDbTimingPoint {
+            bpm: r.read_f64()?,
+            offset: r.read_f64()?,
+            uninherited: r.read_bool()?,
+        });
+    }
+
+    let id = r.read_i32()?;
+    let set_id = r.read_i32()?;
+    let thread_id = r.read_i32()?;
+    let grade_osu = r.read_u8()?;
+    let grade_taiko = r.read_u8()?;
+    let grade_catch = r.read_u8()?;
+    let grade_mania = r.read_u8()?;
+    let local_offset = r.read_i16()?;
+    let stack_leniency = r.read_f32()?;
+    let mode = GameMode::from(r.read_u8()? as u32);
+    let source = r.read_string()?;
+    let tags = r.read_string()?;
+    let online_offset = r.read_i16()?;
+    let title_font = r.read_string()?;
+    let unplayed = r.read_bool()?;
+    let last_played = r.read_i64()?;
+    let is_osz2 = r.read_bool()?;
+    let folder = r.read_string()?;
+    let last_online_check = r.read_i64()?;
+    let ignore_beatmap_sound = r.read_bool()?;
+    let ignore_beatmap_skin = r.read_bool()?;
+    let disable_storyboard = r.read_bool()?;
+    let disable_video = r.read_bool()?;
+    let visual_override = r.read_bool()?;
+    if version < VERSION_FLOAT_DIFFICULTY {
+        r.read_i16()?;
+    }
+    // last modification time again, unused by osu!
+    r.read_i32()?;
+    let mania_scroll_speed = r.read_u8()?;
+
+    Ok(OsuDbBeatmap {
+        artist,
+        artist_unicode,
+        title,
+        title_unicode,
+        creator,
+        difficulty,
+        audio_file,
+        md5,
+        filename,
+        ranked_status,
+        circle_count,
+        slider_count,
+        spinner_count,
+        last_modification_time,
+        ar,
+        cs,
+        hp,
+        od,
+        slider_velocity,
+        star_ratings,
+        drain_time,
+        total_time,
+        preview_time,
+        timing_points,
+        id,
+        set_id,
+        thread_id,
+        grade_osu,
+        grade_taiko,
+        grade_catch,
+        grade_mania,
+        local_offset,
+        stack_leniency,
+        mode,
+        source,
+        tags,
+        online_offset,
+        title_font,
+        unplayed,
+        last_played,
+        is_osz2,
+        folder,
+        last_online_check,
+        ignore_beatmap_sound,
+        ignore_beatmap_skin,
+        disable_storyboard,
+        disable_video,
+        visual_override,
+        mania_scroll_speed,
+    })
+}
+
+/// Path to `osu!.db` of the running osu! instance
+pub fn path(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
+    Ok(crate::db::osu_dir(p, state)?.join("osu!.db"))
+}
+
+/// Reads `osu!.db` of the running osu! instance
+pub fn read(p: &Process, state: &mut State) -> Result<OsuDb, Error> {
+    OsuDb::from_path(path(p, state)?)
+}
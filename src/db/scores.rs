@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::db::binary::BinaryReader;
+use crate::reader::common::GameMode;
+use crate::reader::structs::{Hit, State};
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Target Practice, scores using it store an extra double
+pub const MOD_TARGET_PRACTICE: u32 = 1 << 23;
+/// First score version storing the online score id as a long instead of an int
+pub const VERSION_LONG_SCORE_ID: i32 = 20140721;
+
+/// Size of a score with empty strings and an int online id
+const SCORE_MIN_SIZE: usize = 48;
+
+#[derive(Debug, Clone)]
+pub struct ScoresDbScore {
+    pub mode: GameMode,
+    pub version: i32,
+    pub beatmap_md5: String,
+    pub player: String,
+    pub replay_md5: String,
+    pub hits: Hit,
+    pub score: i32,
+    pub max_combo: i16,
+    pub perfect: bool,
+    pub mods: u32,
+    /// .NET ticks, see `db::binary::ticks_to_unix_millis`
+    pub timestamp: i64,
+    pub online_score_id: i64,
+    /// only set with Target Practice
+    pub additional_mod_info: Option<f64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ScoresDb {
+    pub version: i32,
+    /// local scores indexed by beatmap md5
+    pub beatmaps: HashMap<String, Vec<ScoresDbScore>>,
+}
+
+impl ScoresDb {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = BinaryReader::new(bytes);
+        let version = r.read_i32()?;
+        // md5 and score count
+        let count = r.read_count(5)?;
+        let mut beatmaps = HashMap::with_capacity(count);
+        for _ in 0..count {
+            let md5 = r.read_string()?;
+            let score_count = r.read_count(SCORE_MIN_SIZE)?;
+            let mut scores = Vec::with_capacity(score_count);
+            for _ in 0..score_count {
+                scores.push(read_score(&mut r)?);
+            }
+            beatmaps.entry(md5).or_insert_with(Vec::new).extend(scores);
+        }
+        Ok(Self { version, beatmaps })
+    }
+
+    pub fn scores(&self, md5: &str) -> &[ScoresDbScore] {
+        self.beatmaps.get(md5).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Highest score set on the beatmap, optionally only by the given player
+    pub fn best(&self, md5: &str, player: Option<&str>) -> Option<&ScoresDbScore> {
+        self.scores(md5)
+            .iter()
+            .filter(|s| player.is_none_or(|p| s.player == p))
+            .max_by_key(|s| s.score)
+    }
+}
+
+fn read_score(r: &mut BinaryReader) -> Result<ScoresDbScore, Error> {
+    let mode = GameMode::from(r.read_u8()? as u32);
+    let version = r.read_i32()?;
+    let beatmap_md5 = r.read_string()?;
+    let player = r.read_string()?;
+    let replay_md5 = r.read_string()?;
+    let hits = Hit {
+        _300: r.read_i16()?,
+        _100: r.read_i16()?,
+        _50: r.read_i16()?,
+        _geki: r.read_i16()?,
+        _katu: r.read_i16()?,
+        _miss: r.read_i16()?,
+    };
+    let score = r.read_i32()?;
+    let max_combo = r.read_i16()?;
+    let perfect = r.read_bool()?;
+    let mods = r.read_i32()? as u32;
+    // life bar graph, always empty in scores.db
+    r.read_string()?;
+    let timestamp = r.read_i64()?;
+    // replay data length, always -1 in scores.db
+    r.read_i32()?;
+    let online_score_id = read_online_score_id(r, version)?;
+    let additional_mod_info = if mods & MOD_TARGET_PRACTICE != 0 {
+        Some(r.read_f64()?)
+    } else {
+        None
+    };
+
+    Ok(ScoresDbScore {
+        mode,
+        version,
+        beatmap_md5,
+        player,
+        replay_md5,
+        hits,
+        score,
+        max_combo,
+        perfect,
+        mods,
+        timestamp,
+        online_score_id,
+        additional_mod_info,
+    })
+}
+
+pub(crate) fn read_online_score_id(r: &mut BinaryReader, version: i32) -> Result<i64, Error> {
+    if version >= VERSION_LONG_SCORE_ID {
+        r.read_i64()
+    } else {
+        Ok(r.read_i32()? as i64)
+    }
+}
+
+/// Path to `scores.db` of the running osu! instance
+pub fn path(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
+    Ok(crate::db::osu_dir(p, state)?.join("scores.db"))
+}
+
+/// Reads `scores.db` of the running osu! instance
+pub fn read(p: &Process, state: &mut State) -> Result<ScoresDb, Error> {
+    ScoresDb::from_path(path(p, state)?)
+}
+
+/// Local scores of the currently selected beatmap
+pub fn current_beatmap_scores<'a>(
+    p: &Process,
+    state: &mut State,
+    db: &'a ScoresDb,
+) -> Result<&'a [ScoresDbScore], Error> {
+    let md5 = crate::reader::beatmap::stable::memory::md5(p, state)?;
+    Ok(db.scores(&md5))
+}
@@ -1,5 +1,9 @@
+pub mod db;
 pub mod error;
 pub mod reader;
+pub mod replay;
+pub mod scoring;
+pub mod stats;
 
 pub use error::{Error, Result};
 pub use reader::init_loop;
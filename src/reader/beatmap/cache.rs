@@ -0,0 +1,168 @@
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use crate::reader::beatmap::common::{BeatmapDifficulty, BeatmapDifficultySettings};
+use crate::reader::beatmap::stable::file::difficulty_attributes_for_mode;
+use crate::reader::common::GameMode;
+use crate::Error;
+use rosu_map::section::metadata::Metadata;
+use rosu_map::Beatmap as RmBeatmap;
+use rosu_pp::Beatmap as PpBeatmap;
+
+pub const DEFAULT_BEATMAP_CACHE_CAPACITY: usize = 8;
+
+#[derive(Clone)]
+struct CachedBeatmap {
+    path: PathBuf,
+    md5: String,
+    modified: Option<SystemTime>,
+    // both are parsed lazily, most getters only need one of them
+    map: Option<Arc<RmBeatmap>>,
+    pp_map: Option<Arc<PpBeatmap>>,
+    metadata: Option<Arc<Metadata>>,
+    /// by played mode and mods
+    difficulties: Vec<(GameMode, u32, BeatmapDifficulty)>,
+}
+
+/// LRU cache of parsed `.osu` files used by the file mode getters
+///
+/// Entries are keyed by path + md5 (read from memory) and are invalidated
+/// when the file modification time changes (e.g. saving in the editor)
+#[derive(Clone)]
+pub struct BeatmapCache {
+    capacity: usize,
+    // most recently used first
+    entries: VecDeque<CachedBeatmap>,
+}
+
+impl Default for BeatmapCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_BEATMAP_CACHE_CAPACITY)
+    }
+}
+
+impl BeatmapCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: VecDeque::with_capacity(capacity.max(1)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the beatmap parsed with rosu-map, parsing it only if needed
+    pub fn map(&mut self, path: &Path, md5: &str) -> Result<Arc<RmBeatmap>, Error> {
+        let entry = self.entry(path, md5);
+        if let Some(map) = &entry.map {
+            return Ok(map.clone());
+        }
+        let map = Arc::new(RmBeatmap::from_path(path)?);
+        entry.map = Some(map.clone());
+        Ok(map)
+    }
+
+    /// Returns the beatmap parsed with rosu-pp, parsing it only if needed
+    pub fn pp_map(&mut self, path: &Path, md5: &str) -> Result<Arc<PpBeatmap>, Error> {
+        let entry = self.entry(path, md5);
+        if let Some(pp_map) = &entry.pp_map {
+            return Ok(pp_map.clone());
+        }
+        let pp_map = Arc::new(PpBeatmap::from_path(path)?);
+        entry.pp_map = Some(pp_map.clone());
+        Ok(pp_map)
+    }
+
+    /// Returns the `[Metadata]` section, taken from the rosu-map beatmap when
+    /// it is already parsed, only that section is decoded otherwise
+    pub fn metadata(&mut self, path: &Path, md5: &str) -> Result<Arc<Metadata>, Error> {
+        let entry = self.entry(path, md5);
+        if let Some(metadata) = &entry.metadata {
+            return Ok(metadata.clone());
+        }
+        let metadata = Arc::new(match &entry.map {
+            Some(map) => Metadata {
+                title: map.title.clone(),
+                title_unicode: map.title_unicode.clone(),
+                artist: map.artist.clone(),
+                artist_unicode: map.artist_unicode.clone(),
+                creator: map.creator.clone(),
+                version: map.version.clone(),
+                source: map.source.clone(),
+                tags: map.tags.clone(),
+                beatmap_id: map.beatmap_id,
+                beatmap_set_id: map.beatmap_set_id,
+            },
+            None => rosu_map::from_path::<Metadata>(path)?,
+        });
+        entry.metadata = Some(metadata.clone());
+        Ok(metadata)
+    }
+
+    /// Returns the difficulty of the beatmap played in `mode` with `mods`,
+    /// calculating it only if needed
+    pub fn difficulty(
+        &mut self,
+        path: &Path,
+        md5: &str,
+        mode: GameMode,
+        mods: u32,
+    ) -> Result<BeatmapDifficulty, Error> {
+        let pp_map = self.pp_map(path, md5)?;
+        // `pp_map` moved the entry to the front
+        let entry = &mut self.entries[0];
+        if let Some((.., difficulty)) = entry
+            .difficulties
+            .iter()
+            .find(|(m, mo, _)| *m == mode && *mo == mods)
+        {
+            return Ok(difficulty.clone());
+        }
+        let difficulty = difficulty_attributes_for_mode(
+            &pp_map,
+            mode,
+            &BeatmapDifficultySettings::with_mods(mods),
+        )?;
+        entry.difficulties.push((mode, mods, difficulty.clone()));
+        Ok(difficulty)
+    }
+
+    /// Moves the matching entry to the front, replacing it if it is outdated
+    fn entry(&mut self, path: &Path, md5: &str) -> &mut CachedBeatmap {
+        let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+
+        if let Some(idx) = self.entries.iter().position(|e| e.path == path) {
+            let entry = self.entries.remove(idx).unwrap();
+            if entry.md5 == md5 && entry.modified == modified {
+                self.entries.push_front(entry);
+                return &mut self.entries[0];
+            }
+        }
+
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_back();
+        }
+        self.entries.push_front(CachedBeatmap {
+            path: path.to_path_buf(),
+            md5: md5.to_string(),
+            modified,
+            map: None,
+            pp_map: None,
+            metadata: None,
+            difficulties: Vec::new(),
+        });
+        &mut self.entries[0]
+    }
+}
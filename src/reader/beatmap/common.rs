@@ -1,4 +1,5 @@
 use crate::common::GameMode;
+use rosu_pp::any::DifficultyAttributes;
 
 #[derive(Debug, Clone)]
 pub struct BeatmapInfo {
@@ -52,6 +53,107 @@ pub struct BeatmapStarRating {
     pub ht: f64,
 }
 
+/// Accuracies used by default for pp-for-acc tables
+pub const DEFAULT_PP_ACCURACIES: [f64; 5] = [95.0, 97.0, 98.0, 99.0, 100.0];
+
+/// Settings used to compute difficulty and performance of a beatmap
+///
+/// `ar`, `cs`, `od` and `hp` are difficulty adjust overrides, they still get
+/// modified by the mods (e.g. HR/EZ/DT) like osu! does
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BeatmapDifficultySettings {
+    pub mods: u32,
+    pub clock_rate: Option<f64>,
+    pub ar: Option<f32>,
+    pub cs: Option<f32>,
+    pub od: Option<f32>,
+    pub hp: Option<f32>,
+}
+
+impl BeatmapDifficultySettings {
+    pub fn with_mods(mods: u32) -> Self {
+        Self {
+            mods,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BeatmapDifficulty {
+    pub stars: f64,
+    pub max_combo: u32,
+    /// mode specific attributes (aim/speed/flashlight for std, stamina for taiko...)
+    pub attributes: DifficultyAttributes,
+}
+
+impl BeatmapDifficulty {
+    /// Objects counted in the hits of a play, e.g. only fruits and droplets
+    /// in catch and only circles in taiko
+    pub fn judged_objects(&self) -> u32 {
+        match &self.attributes {
+            DifficultyAttributes::Osu(a) => a.n_circles + a.n_sliders + a.n_spinners,
+            DifficultyAttributes::Taiko(a) => a.max_combo,
+            DifficultyAttributes::Catch(a) => a.n_fruits + a.n_droplets,
+            DifficultyAttributes::Mania(a) => a.n_objects,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BeatmapPpForAcc {
+    pub accuracy: f64,
+    pub pp: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct BeatmapPerformance {
+    pub difficulty: BeatmapDifficulty,
+    pub pp: Vec<BeatmapPpForAcc>,
+}
+
+/// Default amount of points of a strain graph
+pub const DEFAULT_STRAIN_GRAPH_RESOLUTION: usize = 100;
+
+/// A time range in ms of the beatmap, e.g. a kiai or a break
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BeatmapSection {
+    pub start_time: f64,
+    pub end_time: f64,
+}
+
+/// Strain curve of a beatmap ready to be plotted against `game_time`
+///
+/// `strains` are evenly spaced between `start_time` and `end_time` and
+/// normalized between 0 and 1
+#[derive(Debug, Clone)]
+pub struct BeatmapStrainGraph {
+    pub start_time: f64,
+    pub end_time: f64,
+    pub strains: Vec<f64>,
+    pub kiai: Vec<BeatmapSection>,
+    pub breaks: Vec<BeatmapSection>,
+}
+
+impl BeatmapStrainGraph {
+    /// Time in ms between two points of the graph
+    pub fn point_duration(&self) -> f64 {
+        if self.strains.is_empty() {
+            return 0.0;
+        }
+        (self.end_time - self.start_time) / self.strains.len() as f64
+    }
+
+    /// Index of the point being played at the given time, `None` if outside the graph
+    pub fn point_at(&self, time: f64) -> Option<usize> {
+        let duration = self.point_duration();
+        if duration <= 0.0 || time < self.start_time || time >= self.end_time {
+            return None;
+        }
+        Some(((time - self.start_time) / duration) as usize)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BeatmapStats {
     pub ar: f32,
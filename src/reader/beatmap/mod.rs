@@ -1,13 +1,18 @@
+pub mod cache;
 pub mod common;
 pub mod stable;
 
 use std::path::PathBuf;
 
 use crate::impl_osu_accessor;
+use crate::reader::beatmap::common::BeatmapDifficulty;
+use crate::reader::beatmap::common::BeatmapDifficultySettings;
 use crate::reader::beatmap::common::BeatmapInfo;
+use crate::reader::beatmap::common::BeatmapPerformance;
 use crate::reader::beatmap::common::BeatmapStarRating;
 use crate::reader::beatmap::common::BeatmapStats;
 use crate::reader::beatmap::common::BeatmapStatus;
+use crate::reader::beatmap::common::BeatmapStrainGraph;
 use crate::reader::common::GameMode;
 use crate::reader::common::OsuClientKind;
 use crate::reader::structs::State;
@@ -63,4 +68,50 @@ impl<'a> BeatmapReader<'a> {
         fn audio_path() -> PathBuf => stable::file::audio_path,
         fn star_rating() -> BeatmapStarRating => stable::file::star_rating,
     }
+
+    pub fn difficulty_attributes(
+        &mut self,
+        settings: &BeatmapDifficultySettings,
+    ) -> Result<BeatmapDifficulty, Error> {
+        match self.osu_type {
+            OsuClientKind::Stable => {
+                stable::file::difficulty_attributes(self.process, self.state, settings)
+            }
+            _ => Err(Error::Unsupported(
+                "Unsupported osu type for now".to_string(),
+            )),
+        }
+    }
+
+    /// pp for each accuracy, see `common::DEFAULT_PP_ACCURACIES` for the usual table
+    pub fn performance(
+        &mut self,
+        settings: &BeatmapDifficultySettings,
+        accuracies: &[f64],
+    ) -> Result<BeatmapPerformance, Error> {
+        match self.osu_type {
+            OsuClientKind::Stable => {
+                stable::file::performance(self.process, self.state, settings, accuracies)
+            }
+            _ => Err(Error::Unsupported(
+                "Unsupported osu type for now".to_string(),
+            )),
+        }
+    }
+
+    /// Strain graph with `resolution` points, see `common::DEFAULT_STRAIN_GRAPH_RESOLUTION`
+    pub fn strain_graph(
+        &mut self,
+        settings: &BeatmapDifficultySettings,
+        resolution: usize,
+    ) -> Result<BeatmapStrainGraph, Error> {
+        match self.osu_type {
+            OsuClientKind::Stable => {
+                stable::file::strain_graph(self.process, self.state, settings, resolution)
+            }
+            _ => Err(Error::Unsupported(
+                "Unsupported osu type for now".to_string(),
+            )),
+        }
+    }
 }
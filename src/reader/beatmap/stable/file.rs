@@ -1,9 +1,11 @@
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::common::GameMode;
 use crate::reader::beatmap::common::{
-    BeatmapInfo, BeatmapLocation, BeatmapMetadata, BeatmapStarRating, BeatmapStats, BeatmapStatus,
-    BeatmapTechnicalInfo,
+    BeatmapDifficulty, BeatmapDifficultySettings, BeatmapInfo, BeatmapLocation, BeatmapMetadata,
+    BeatmapPerformance, BeatmapPpForAcc, BeatmapSection, BeatmapStarRating, BeatmapStats,
+    BeatmapStatus, BeatmapStrainGraph, BeatmapTechnicalInfo,
 };
 use crate::reader::beatmap::stable::memory::{audio, filename, folder};
 use crate::reader::beatmap::stable::{beatmap_addr, offset::BEATMAP_OFFSET};
@@ -13,6 +15,7 @@ use crate::Error;
 use rosu_map::section::hit_objects::HitObjectKind;
 use rosu_map::Beatmap as RmBeatmap;
 use rosu_mem::process::{Process, ProcessTraits};
+use rosu_pp::model::hit_object::HitObjectKind as PpHitObjectKind;
 
 pub fn path(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
     let folder = folder(p, state)?;
@@ -30,6 +33,20 @@ pub fn audio_path(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
     Ok(songs_path.join(folder).join(audio))
 }
 
+/// Returns the current beatmap parsed with rosu-map, cached in `state.beatmap_cache`
+pub fn parsed(p: &Process, state: &mut State) -> Result<Arc<RmBeatmap>, Error> {
+    let path = path(p, state)?;
+    let md5 = md5(p, state)?;
+    state.beatmap_cache.map(&path, &md5)
+}
+
+/// Returns the current beatmap parsed with rosu-pp, cached in `state.beatmap_cache`
+pub fn parsed_pp(p: &Process, state: &mut State) -> Result<Arc<rosu_pp::Beatmap>, Error> {
+    let path = path(p, state)?;
+    let md5 = md5(p, state)?;
+    state.beatmap_cache.pp_map(&path, &md5)
+}
+
 // generate getters that use the default logic
 macro_rules! generate_beatmap_field_getter {
     (
@@ -37,9 +54,8 @@ macro_rules! generate_beatmap_field_getter {
     ) => {
         $(
             pub fn $fn_name(p: &Process, state: &mut State) -> Result<$ret_ty, Error> {
-                let path = path(p, state)?;
-                let b = RmBeatmap::from_path(path)?;
-                Ok(b.$field)
+                let b = parsed(p, state)?;
+                Ok(b.$field.clone())
             }
         )*
     };
@@ -51,8 +67,7 @@ macro_rules! generate_beatmap_custom_getter_safe {
     ) => {
         $(
             pub fn $fn_name(p: &Process, state: &mut State) -> Result<$ret_ty, Error> {
-                let path = path(p, state)?;
-                let $b = RmBeatmap::from_path(path)?;
+                let $b = parsed(p, state)?;
                 $body
             }
         )*
@@ -75,24 +90,6 @@ generate_beatmap_field_getter! {
 }
 
 generate_beatmap_custom_getter_safe! {
-    slider_count: i32 = |b| {
-        Ok(b.hit_objects.iter()
-            .filter(|h| matches!(h.kind, HitObjectKind::Slider(_)))
-            .count() as i32)
-    }
-    object_count: u32 = |b| {
-        Ok(b.hit_objects.len() as u32)
-    }
-    length: i32 = |b| {
-        let last = b.hit_objects.last().ok_or_else(|| Error::Other("Empty hitobject list".into()))?;
-
-        let duration = match &last.kind {
-            HitObjectKind::Hold(hold_data) => last.start_time + hold_data.duration,
-            _ => last.start_time,
-        };
-
-        Ok(duration as i32)
-    }
     drain_time: i32 = |b| {
         let first = b.hit_objects.first().ok_or_else(|| Error::Other("Empty hitobject list".into()))?;
         let last = b.hit_objects.last().ok_or_else(|| Error::Other("Empty hitobject list".into()))?;
@@ -110,6 +107,53 @@ generate_beatmap_custom_getter_safe! {
 
 }
 
+pub fn slider_count(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let b = parsed_pp(p, state)?;
+    Ok(slider_count_from_beatmap(&b))
+}
+
+pub fn object_count(p: &Process, state: &mut State) -> Result<u32, Error> {
+    let b = parsed_pp(p, state)?;
+    Ok(b.hit_objects.len() as u32)
+}
+
+pub fn length(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let b = parsed_pp(p, state)?;
+    length_from_beatmap(&b)
+}
+
+fn length_from_beatmap(b: &rosu_pp::Beatmap) -> Result<i32, Error> {
+    let last = b
+        .hit_objects
+        .last()
+        .ok_or_else(|| Error::Other("Empty hitobject list".into()))?;
+
+    let duration = match &last.kind {
+        PpHitObjectKind::Hold(hold_data) => last.start_time + hold_data.duration,
+        _ => last.start_time,
+    };
+
+    Ok(duration as i32)
+}
+
+fn slider_count_from_beatmap(b: &rosu_pp::Beatmap) -> i32 {
+    b.hit_objects.iter().filter(|h| h.is_slider()).count() as i32
+}
+
+/// Stats of an already parsed beatmap, star ratings included
+pub fn stats_from_beatmap(b: &rosu_pp::Beatmap) -> Result<BeatmapStats, Error> {
+    Ok(BeatmapStats {
+        ar: b.ar,
+        od: b.od,
+        cs: b.cs,
+        hp: b.hp,
+        length: length_from_beatmap(b)?,
+        star_rating: star_rating_from_beatmap(b),
+        object_count: b.hit_objects.len() as i32,
+        slider_count: slider_count_from_beatmap(b),
+    })
+}
+
 // cant do this in file mode
 pub fn status(p: &Process, state: &mut State) -> Result<BeatmapStatus, Error> {
     // cant do this in file mode
@@ -117,78 +161,267 @@ pub fn status(p: &Process, state: &mut State) -> Result<BeatmapStatus, Error> {
 }
 
 pub fn star_rating(p: &Process, state: &mut State) -> Result<BeatmapStarRating, Error> {
-    let folder = folder(p, state)?;
-    let filename = filename(p, state)?;
-    let songs_path = path_folder(p, state)?;
-    let path = songs_path.join(folder).join(filename);
-    let b = rosu_pp::Beatmap::from_path(path)?;
-    let diff_attrs = rosu_pp::Difficulty::new().calculate(&b);
-    let diff_dt = rosu_pp::Difficulty::new().mods(64).calculate(&b);
-    let diff_ht = rosu_pp::Difficulty::new().mods(256).calculate(&b);
-    let no_mod = diff_attrs.stars();
-    let dt = diff_dt.stars();
-    let ht = diff_ht.stars();
-    Ok(BeatmapStarRating { no_mod, dt, ht })
+    let b = parsed_pp(p, state)?;
+    Ok(star_rating_from_beatmap(&b))
+}
+
+/// Computes nomod/DT/HT star ratings from an already parsed beatmap
+pub fn star_rating_from_beatmap(b: &rosu_pp::Beatmap) -> BeatmapStarRating {
+    let stars = |mods: u32| {
+        difficulty_attributes_from_beatmap(b, &BeatmapDifficultySettings::with_mods(mods)).stars
+    };
+    BeatmapStarRating {
+        no_mod: stars(0),
+        dt: stars(64),
+        ht: stars(256),
+    }
+}
+
+fn difficulty_calculator(settings: &BeatmapDifficultySettings) -> rosu_pp::Difficulty {
+    let mut diff = rosu_pp::Difficulty::new().mods(settings.mods);
+    if let Some(clock_rate) = settings.clock_rate {
+        diff = diff.clock_rate(clock_rate);
+    }
+    if let Some(ar) = settings.ar {
+        diff = diff.ar(ar, false);
+    }
+    if let Some(cs) = settings.cs {
+        diff = diff.cs(cs, false);
+    }
+    if let Some(od) = settings.od {
+        diff = diff.od(od, false);
+    }
+    if let Some(hp) = settings.hp {
+        diff = diff.hp(hp, false);
+    }
+    diff
+}
+
+/// Computes difficulty attributes of an already parsed beatmap for the given settings
+pub fn difficulty_attributes_from_beatmap(
+    b: &rosu_pp::Beatmap,
+    settings: &BeatmapDifficultySettings,
+) -> BeatmapDifficulty {
+    let attributes = difficulty_calculator(settings).calculate(b);
+    BeatmapDifficulty {
+        stars: attributes.stars(),
+        max_combo: attributes.max_combo(),
+        attributes,
+    }
+}
+
+/// Computes difficulty attributes of an already parsed beatmap played in
+/// `mode`, converting osu!standard beatmaps when needed
+pub fn difficulty_attributes_for_mode(
+    b: &rosu_pp::Beatmap,
+    mode: GameMode,
+    settings: &BeatmapDifficultySettings,
+) -> Result<BeatmapDifficulty, Error> {
+    let mode = match mode {
+        GameMode::Osu => rosu_pp::model::mode::GameMode::Osu,
+        GameMode::Taiko => rosu_pp::model::mode::GameMode::Taiko,
+        GameMode::Catch => rosu_pp::model::mode::GameMode::Catch,
+        GameMode::Mania => rosu_pp::model::mode::GameMode::Mania,
+        GameMode::Unknown => {
+            return Err(Error::Unsupported("Unknown game mode".to_string()));
+        }
+    };
+    let map = b
+        .convert_ref(mode, &settings.mods.into())
+        .map_err(|e| Error::Unsupported(format!("Cannot convert the beatmap: {e}")))?;
+    Ok(difficulty_attributes_from_beatmap(&map, settings))
+}
+
+/// Difficulty of the current beatmap played in `mode` with `mods`, cached in
+/// `state.beatmap_cache` with the beatmap
+pub fn cached_difficulty(
+    p: &Process,
+    state: &mut State,
+    mode: GameMode,
+    mods: u32,
+) -> Result<BeatmapDifficulty, Error> {
+    let path = path(p, state)?;
+    let md5 = md5(p, state)?;
+    state.beatmap_cache.difficulty(&path, &md5, mode, mods)
+}
+
+/// Computes difficulty attributes and the pp for each accuracy (in percent)
+/// of an already parsed beatmap, difficulty is only calculated once
+pub fn performance_from_beatmap(
+    b: &rosu_pp::Beatmap,
+    settings: &BeatmapDifficultySettings,
+    accuracies: &[f64],
+) -> BeatmapPerformance {
+    let calculator = difficulty_calculator(settings);
+    let difficulty = difficulty_attributes_from_beatmap(b, settings);
+    let pp = accuracies
+        .iter()
+        .map(|&accuracy| BeatmapPpForAcc {
+            accuracy,
+            pp: rosu_pp::Performance::new(difficulty.attributes.clone())
+                .difficulty(calculator.clone())
+                .accuracy(accuracy)
+                .calculate()
+                .pp(),
+        })
+        .collect();
+    BeatmapPerformance { difficulty, pp }
+}
+
+pub fn difficulty_attributes(
+    p: &Process,
+    state: &mut State,
+    settings: &BeatmapDifficultySettings,
+) -> Result<BeatmapDifficulty, Error> {
+    let b = parsed_pp(p, state)?;
+    Ok(difficulty_attributes_from_beatmap(&b, settings))
+}
+
+pub fn performance(
+    p: &Process,
+    state: &mut State,
+    settings: &BeatmapDifficultySettings,
+    accuracies: &[f64],
+) -> Result<BeatmapPerformance, Error> {
+    let b = parsed_pp(p, state)?;
+    Ok(performance_from_beatmap(&b, settings, accuracies))
 }
 
 pub fn md5(p: &Process, state: &mut State) -> Result<String, Error> {
     // TODO: implement this for now will get from memory
     crate::reader::beatmap::stable::memory::md5(p, state)
 }
-pub fn stats(p: &Process, state: &mut State) -> Result<BeatmapStats, Error> {
-    let beatmap_addr = path(p, state)?;
-    let b = RmBeatmap::from_path(beatmap_addr)?;
-    Ok(BeatmapStats {
-        ar: b.approach_rate,
-        od: b.overall_difficulty,
-        cs: b.circle_size,
-        hp: b.hp_drain_rate,
-        length: length(p, state)?,
-        star_rating: star_rating(p, state)?,
-        object_count: b.hit_objects.len() as i32,
-        slider_count: b
-            .hit_objects
-            .iter()
-            .filter(|h| matches!(h.kind, HitObjectKind::Slider(_)))
-            .count() as i32,
+/// Builds the strain graph of an already parsed beatmap, `map` is used for
+/// timings, kiai and breaks while `pp_map` is used for the strains
+pub fn strain_graph_from_beatmap(
+    map: &RmBeatmap,
+    pp_map: &rosu_pp::Beatmap,
+    settings: &BeatmapDifficultySettings,
+    resolution: usize,
+) -> Result<BeatmapStrainGraph, Error> {
+    let first = map
+        .hit_objects
+        .first()
+        .ok_or_else(|| Error::Other("Empty hitobject list".into()))?;
+    let start_time = first.start_time;
+    let end_time = length_from_beatmap(pp_map)? as f64;
+
+    // combine the skills the same way most overlays do (flashlight is left out)
+    let raw: Vec<f64> = match difficulty_calculator(settings).strains(pp_map) {
+        rosu_pp::any::Strains::Osu(s) => sum_strains(&[&s.aim, &s.speed]),
+        rosu_pp::any::Strains::Taiko(s) => {
+            sum_strains(&[&s.color, &s.reading, &s.rhythm, &s.stamina])
+        }
+        rosu_pp::any::Strains::Catch(s) => s.movement,
+        rosu_pp::any::Strains::Mania(s) => s.strains,
+    };
+
+    // strain sections cover the whole map so they can be bucketed by index
+    let mut strains = Vec::with_capacity(resolution);
+    if !raw.is_empty() {
+        for i in 0..resolution {
+            let from = i * raw.len() / resolution;
+            let to = ((i + 1) * raw.len() / resolution).max(from + 1);
+            let peak = raw[from..to.min(raw.len())]
+                .iter()
+                .copied()
+                .fold(0.0, f64::max);
+            strains.push(peak);
+        }
+    }
+    let max = strains.iter().copied().fold(0.0, f64::max);
+    if max > 0.0 {
+        strains.iter_mut().for_each(|s| *s /= max);
+    }
+
+    let mut kiai = Vec::new();
+    let mut kiai_start = None;
+    for point in &map.control_points.effect_points {
+        match (point.kiai, kiai_start) {
+            (true, None) => kiai_start = Some(point.time),
+            (false, Some(start)) => {
+                kiai.push(BeatmapSection {
+                    start_time: start,
+                    end_time: point.time,
+                });
+                kiai_start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(start) = kiai_start {
+        kiai.push(BeatmapSection {
+            start_time: start,
+            end_time: end_time.max(start),
+        });
+    }
+
+    let breaks = map
+        .breaks
+        .iter()
+        .map(|b| BeatmapSection {
+            start_time: b.start_time,
+            end_time: b.end_time,
+        })
+        .collect();
+
+    Ok(BeatmapStrainGraph {
+        start_time,
+        end_time,
+        strains,
+        kiai,
+        breaks,
     })
 }
 
+fn sum_strains(skills: &[&Vec<f64>]) -> Vec<f64> {
+    let len = skills.iter().map(|s| s.len()).max().unwrap_or(0);
+    (0..len)
+        .map(|i| skills.iter().filter_map(|s| s.get(i)).sum())
+        .collect()
+}
+
+pub fn strain_graph(
+    p: &Process,
+    state: &mut State,
+    settings: &BeatmapDifficultySettings,
+    resolution: usize,
+) -> Result<BeatmapStrainGraph, Error> {
+    let map = parsed(p, state)?;
+    let pp_map = parsed_pp(p, state)?;
+    strain_graph_from_beatmap(&map, &pp_map, settings, resolution)
+}
+
+pub fn stats(p: &Process, state: &mut State) -> Result<BeatmapStats, Error> {
+    let b = parsed_pp(p, state)?;
+    stats_from_beatmap(&b)
+}
+
 pub fn info(p: &Process, state: &mut State) -> Result<BeatmapInfo, Error> {
-    let beatmap_file = path(p, state)?;
     let beatmap_addr = beatmap_addr(p, state)?;
-    let b = RmBeatmap::from_path(beatmap_file)?;
+    let path = path(p, state)?;
+    let md5 = md5(p, state)?;
+    // the .osu is only parsed by rosu-pp, metadata is read from its own section
+    let b = state.beatmap_cache.pp_map(&path, &md5)?;
+    let metadata = state.beatmap_cache.metadata(&path, &md5)?;
     // done like that to be more efficient reading the string one by one would need to reload addr everytime which cost more
     Ok(BeatmapInfo {
         technical: BeatmapTechnicalInfo {
-            md5: crate::reader::beatmap::stable::file::md5(p, state)?,
-            id: b.beatmap_id,
-            set_id: b.beatmap_set_id,
-            mode: GameMode::Osu,
+            md5,
+            id: metadata.beatmap_id,
+            set_id: metadata.beatmap_set_id,
+            mode: GameMode::from(b.mode as u32),
             ranked_status: status(p, state)?,
         },
         metadata: BeatmapMetadata {
-            author: b.artist,
-            creator: b.creator,
-            title_romanized: b.title,
-            title_original: b.title_unicode,
-            difficulty: b.version,
-            tags: b.tags,
-        },
-        stats: BeatmapStats {
-            ar: b.approach_rate,
-            od: b.overall_difficulty,
-            cs: b.circle_size,
-            hp: b.hp_drain_rate,
-            length: length(p, state)?,
-            star_rating: star_rating(p, state)?,
-            object_count: b.hit_objects.len() as i32,
-            slider_count: b
-                .hit_objects
-                .iter()
-                .filter(|h| matches!(h.kind, HitObjectKind::Slider(_)))
-                .count() as i32,
+            author: metadata.artist.clone(),
+            creator: metadata.creator.clone(),
+            title_romanized: metadata.title.clone(),
+            title_original: metadata.title_unicode.clone(),
+            difficulty: metadata.version.clone(),
+            tags: metadata.tags.clone(),
         },
+        stats: stats_from_beatmap(&b)?,
         location: BeatmapLocation {
             folder: p.read_string(beatmap_addr + BEATMAP_OFFSET.location.folder)?,
             filename: p.read_string(beatmap_addr + BEATMAP_OFFSET.location.filename)?,
@@ -0,0 +1,38 @@
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatMessage {
+    /// as shown in the chat, e.g. `12:34`
+    pub time: String,
+    pub sender: String,
+    pub content: String,
+}
+
+impl ChatMessage {
+    /// Splits the `12:34 peppy:` header osu! keeps before the content, the
+    /// whole header is the sender when it has no time
+    pub fn from_header(header: &str, content: String) -> Self {
+        let header = header.trim();
+        let (time, sender) = match header.split_once(' ') {
+            Some((time, sender)) if time.contains(':') => (time, sender),
+            _ => ("", header),
+        };
+        Self {
+            time: time.to_string(),
+            sender: sender.trim().trim_end_matches(':').to_string(),
+            content,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatChannel {
+    /// e.g. `#osu`, `#multiplayer` or the name of the user for private messages
+    pub name: String,
+    /// oldest first, osu! drops the oldest ones past its limit
+    pub messages: Vec<ChatMessage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewChatMessage {
+    pub channel: String,
+    pub message: ChatMessage,
+}
@@ -0,0 +1,50 @@
+pub mod common;
+pub mod stable;
+pub mod tracker;
+
+use crate::impl_osu_accessor;
+#[cfg(feature = "unverified-chat")]
+use crate::reader::chat::common::ChatChannel;
+use crate::reader::common::OsuClientKind;
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// In-game chat, see [`tracker::ChatTracker`] to only get new messages
+pub struct ChatReader<'a> {
+    pub process: &'a Process,
+    pub state: &'a mut State,
+    pub osu_type: OsuClientKind,
+}
+
+impl<'a> ChatReader<'a> {
+    pub fn new(p: &'a Process, state: &'a mut State, osu_type: OsuClientKind) -> Self {
+        Self {
+            process: p,
+            state,
+            osu_type,
+        }
+    }
+    impl_osu_accessor! {
+        fn is_open() -> bool => stable::memory::is_open,
+    }
+}
+
+/// The channel list offset is not verified on a real client yet, it needs
+/// the `unverified-chat` feature
+#[cfg(feature = "unverified-chat")]
+impl<'a> ChatReader<'a> {
+    impl_osu_accessor! {
+        fn channel_names() -> Vec<String> => stable::memory::channel_names,
+        fn channels() -> Vec<ChatChannel> => stable::memory::channels,
+    }
+
+    pub fn channel(&mut self, name: &str) -> Result<Option<ChatChannel>, Error> {
+        match self.osu_type {
+            OsuClientKind::Stable => stable::memory::channel(self.process, self.state, name),
+            _ => Err(Error::Unsupported(
+                "Unsupported osu type for now".to_string(),
+            )),
+        }
+    }
+}
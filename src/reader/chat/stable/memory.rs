@@ -0,0 +1,86 @@
+#[cfg(feature = "unverified-chat")]
+use crate::reader::chat::common::ChatChannel;
+use crate::reader::chat::common::ChatMessage;
+use crate::reader::chat::stable::offset::CHAT_OFFSET;
+use crate::reader::helpers::read_list;
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::{Process, ProcessTraits};
+use std::mem::size_of;
+
+pub fn chat_state_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    Ok(p.read_i32(state.addresses.chat_checker + CHAT_OFFSET.state_ptr)?)
+}
+
+pub fn is_open(p: &Process, state: &mut State) -> Result<bool, Error> {
+    let chat_state_addr = chat_state_addr(p, state)?;
+    Ok(p.read_i32(chat_state_addr)? != 0)
+}
+
+#[cfg(feature = "unverified-chat")]
+pub fn channels_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let chat_state_addr = chat_state_addr(p, state)?;
+    let addr = p.read_i32(chat_state_addr + CHAT_OFFSET.channels)?;
+    if addr == 0 {
+        return Err(Error::NotAvailable("Chat not loaded".to_string()));
+    }
+    Ok(addr)
+}
+
+#[cfg(feature = "unverified-chat")]
+pub fn channel_names(p: &Process, state: &mut State) -> Result<Vec<String>, Error> {
+    let channels_addr = channels_addr(p, state)?;
+    read_channel_addrs(p, channels_addr)?
+        .into_iter()
+        .map(|channel_addr| Ok(p.read_string(channel_addr + CHAT_OFFSET.channel.name)?))
+        .collect()
+}
+
+#[cfg(feature = "unverified-chat")]
+pub fn channels(p: &Process, state: &mut State) -> Result<Vec<ChatChannel>, Error> {
+    let channels_addr = channels_addr(p, state)?;
+    read_channel_addrs(p, channels_addr)?
+        .into_iter()
+        .map(|channel_addr| {
+            Ok(ChatChannel {
+                name: p.read_string(channel_addr + CHAT_OFFSET.channel.name)?,
+                messages: read_messages(
+                    p,
+                    p.read_i32(channel_addr + CHAT_OFFSET.channel.messages)?,
+                )?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(feature = "unverified-chat")]
+/// Messages of the channel named `name`, e.g. `#multiplayer`
+pub fn channel(p: &Process, state: &mut State, name: &str) -> Result<Option<ChatChannel>, Error> {
+    Ok(channels(p, state)?.into_iter().find(|c| c.name == name))
+}
+
+#[cfg(feature = "unverified-chat")]
+fn read_channel_addrs(p: &Process, channels_addr: i32) -> Result<Vec<i32>, Error> {
+    let (items, size) = read_list(p, channels_addr)?;
+    (0..size)
+        .map(|i| Ok(p.read_i32(items + (i * size_of::<i32>()) as i32)?))
+        .collect()
+}
+
+/// Reads a `List<Message>` of a chat channel or of the tourney manager
+pub(crate) fn read_messages(p: &Process, messages_addr: i32) -> Result<Vec<ChatMessage>, Error> {
+    if messages_addr == 0 {
+        return Ok(Vec::new());
+    }
+    let offset = &CHAT_OFFSET.message;
+    let (items, size) = read_list(p, messages_addr)?;
+    (0..size)
+        .map(|i| {
+            let message_addr = p.read_i32(items + (i * size_of::<i32>()) as i32)?;
+            Ok(ChatMessage::from_header(
+                &p.read_string(message_addr + offset.header)?,
+                p.read_string(message_addr + offset.content)?,
+            ))
+        })
+        .collect()
+}
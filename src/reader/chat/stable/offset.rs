@@ -0,0 +1,40 @@
+pub struct ChatOffset {
+    pub state_ptr: i32,
+    pub channels: i32,
+    pub channel: ChatChannelOffset,
+    pub message: ChatMessageOffset,
+}
+
+// `state_ptr` is the operand of the chat_checker signature, a static holding
+// the chat display state (0 when hidden). The channel list is assumed to be the
+// static next to it, this is not verified on a real client so everything
+// reading it is behind the `unverified-chat` feature
+pub(crate) const CHAT_OFFSET: ChatOffset = ChatOffset {
+    state_ptr: 0x4,
+    channels: 0x8, // List<Channel>
+    channel: CHAT_CHANNEL_OFFSET,
+    message: CHAT_MESSAGE_OFFSET,
+};
+
+pub struct ChatChannelOffset {
+    pub name: i32,
+    pub messages: i32,
+}
+
+pub(crate) const CHAT_CHANNEL_OFFSET: ChatChannelOffset = ChatChannelOffset {
+    name: 0x4,
+    messages: 0x10, // List<Message>
+};
+
+/// `Message` of the chat window and of the tourney manager
+pub struct ChatMessageOffset {
+    pub content: i32,
+    /// `12:34 peppy:`, time and sender in one string as shown in the chat
+    pub header: i32,
+}
+
+// the layout tosu reads messages with
+pub(crate) const CHAT_MESSAGE_OFFSET: ChatMessageOffset = ChatMessageOffset {
+    content: 0x4,
+    header: 0x8,
+};
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::reader::chat::common::{ChatChannel, ChatMessage, NewChatMessage};
+#[cfg(feature = "unverified-chat")]
+use crate::reader::chat::stable::memory::channels;
+#[cfg(feature = "unverified-chat")]
+use crate::reader::structs::State;
+#[cfg(feature = "unverified-chat")]
+use crate::Error;
+#[cfg(feature = "unverified-chat")]
+use rosu_mem::process::Process;
+
+/// Returns only the messages that were not seen on the previous update
+///
+/// osu! drops the oldest messages past its limit so the count of a channel
+/// can stay the same while new messages come in, the last seen message is
+/// looked for instead
+#[derive(Debug, Clone, Default)]
+pub struct ChatTracker {
+    last_seen: HashMap<String, (ChatMessage, usize)>,
+}
+
+impl ChatTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn reset(&mut self) {
+        self.last_seen.clear();
+    }
+
+    /// Needs the `unverified-chat` feature, see [`crate::reader::chat::ChatReader`]
+    #[cfg(feature = "unverified-chat")]
+    pub fn update(&mut self, p: &Process, state: &mut State) -> Result<Vec<NewChatMessage>, Error> {
+        Ok(self.push(&channels(p, state)?))
+    }
+
+    /// Feeds the channels read from memory
+    pub fn push(&mut self, channels: &[ChatChannel]) -> Vec<NewChatMessage> {
+        let mut new_messages = Vec::new();
+        for channel in channels {
+            let start = match self.last_seen.get(&channel.name) {
+                Some((last, count)) => new_start(&channel.messages, last, *count),
+                None => 0,
+            };
+            new_messages.extend(
+                channel.messages[start..]
+                    .iter()
+                    .map(|message| NewChatMessage {
+                        channel: channel.name.clone(),
+                        message: message.clone(),
+                    }),
+            );
+            if let Some(last) = channel.messages.last() {
+                self.last_seen.insert(
+                    channel.name.clone(),
+                    (last.clone(), same_messages(&channel.messages, last)),
+                );
+            }
+        }
+        new_messages
+    }
+}
+
+/// Times `last` appears in `messages`, to tell apart repeated messages
+fn same_messages(messages: &[ChatMessage], last: &ChatMessage) -> usize {
+    messages.iter().filter(|m| *m == last).count()
+}
+
+/// Index of the first new message, after the `count`th copy of `last` from
+/// the end when it's still there
+fn new_start(messages: &[ChatMessage], last: &ChatMessage, count: usize) -> usize {
+    let seen_copies = same_messages(messages, last);
+    if seen_copies == 0 {
+        // everything seen was dropped
+        return 0;
+    }
+    // copies of the last message sent since are new
+    let new_copies = seen_copies.saturating_sub(count);
+    messages
+        .iter()
+        .enumerate()
+        .filter(|(_, m)| *m == last)
+        .map(|(i, _)| i)
+        .rev()
+        .nth(new_copies)
+        .map_or(0, |i| i + 1)
+}
@@ -54,6 +54,69 @@ impl GameMode {
     }
 }
 
+/// Grade of a play, `XH`/`SH` are the silver SS/S given with HD, FL or FadeIn
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Grade {
+    XH,
+    X,
+    SH,
+    S,
+    A,
+    B,
+    C,
+    #[default]
+    D,
+}
+
+impl Grade {
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        match self {
+            Grade::XH => "SSH".to_string(),
+            Grade::X => "SS".to_string(),
+            Grade::SH => "SH".to_string(),
+            Grade::S => "S".to_string(),
+            Grade::A => "A".to_string(),
+            Grade::B => "B".to_string(),
+            Grade::C => "C".to_string(),
+            Grade::D => "D".to_string(),
+        }
+    }
+}
+
+/// Mods played by osu! itself (Autoplay, Cinema)
+const AUTOPLAY_MODS: u32 = (1 << 11) | (1 << 22);
+
+/// Whose play is shown, only `Play` is a play of the logged in user
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum PlayKind {
+    #[default]
+    Play,
+    Replay,
+    Spectating,
+    Autoplay,
+}
+
+impl PlayKind {
+    /// Autoplay and spectating are also played as replays by osu!, so they
+    /// are checked first
+    pub fn new(mods: u32, is_spectating: bool, is_replay: bool) -> Self {
+        if mods & AUTOPLAY_MODS != 0 {
+            Self::Autoplay
+        } else if is_spectating {
+            Self::Spectating
+        } else if is_replay {
+            Self::Replay
+        } else {
+            Self::Play
+        }
+    }
+
+    pub fn is_own_play(&self) -> bool {
+        *self == Self::Play
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
 #[repr(u32)]
 pub enum GameState {
@@ -75,6 +138,8 @@ pub enum GameState {
     MultiplayerResultScreenTagCoop = 17,
     MultiplayerResultScreenTeamVs = 18,
     SongImport = 19,
+    /// Tournament manager, tourney clients report the usual states
+    Tourney = 22,
     #[default]
     Unknown,
 }
@@ -101,11 +166,25 @@ impl From<u32> for GameState {
             17 => Self::MultiplayerResultScreenTagCoop,
             18 => Self::MultiplayerResultScreenTeamVs,
             19 => Self::SongImport,
+            22 => Self::Tourney,
             _ => Self::Unknown,
         }
     }
 }
 
+impl GameState {
+    /// Solo and multiplayer result screens, where a completed play ends up
+    pub fn is_result_screen(&self) -> bool {
+        matches!(
+            self,
+            Self::ResultScreen
+                | Self::MultiplayerResultScreen
+                | Self::MultiplayerResultScreenTagCoop
+                | Self::MultiplayerResultScreenTeamVs
+        )
+    }
+}
+
 pub struct CommonReader<'a> {
     pub process: &'a Process,
     pub state: &'a mut State,
@@ -125,6 +204,9 @@ impl<'a> CommonReader<'a> {
         fn game_state() -> GameState => stable::memory::game_state,
         fn menu_game_mode() -> u32 => stable::memory::menu_game_mode,
         fn path_folder() -> PathBuf => stable::memory::path_folder,
+        fn is_watching_replay() -> bool => stable::memory::is_watching_replay,
+        fn is_spectating() -> bool => stable::memory::is_spectating,
+        fn spectating_username() -> Option<String> => stable::memory::spectating_username,
     }
 
     pub fn check_game_state(&mut self, g_state: GameState) -> Result<bool, Error> {
@@ -2,9 +2,11 @@ use std::path::PathBuf;
 
 use crate::generate_offset_getter;
 use crate::reader::common::stable::offset::COMMON_OFFSET;
-use crate::reader::common::GameState;
+use crate::reader::common::{GameState, PlayKind};
 use crate::reader::helpers::{read_i32, read_u32};
-use crate::reader::structs::State;
+use crate::reader::settings::config::OsuConfig;
+use crate::reader::structs::{optional_address, State};
+use crate::reader::wine::resolve_path;
 use crate::Error;
 use rosu_mem::process::{Process, ProcessTraits};
 
@@ -16,22 +18,31 @@ pub fn status_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
 ///
 /// **Platform-specific**
 /// - Windows: Will return full absolute path to the `Songs` folder
-/// - Linux: Might return relative path, carefully check by yourself
+/// - Linux: Windows paths are translated with the wine prefix of osu!, see
+///   [`crate::reader::wine`]
+///
+/// Falls back to `BeatmapDirectory` of the config files when the path in
+/// memory is empty or can't be read, the signatures are needed to get here,
+/// use [`OsuConfig`] directly without them
 pub(crate) fn path_folder(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
-    let settings_ptr = p.read_i32(state.addresses.settings + COMMON_OFFSET.settings_ptr)?;
-    let settings_addr = p.read_i32(settings_ptr + COMMON_OFFSET.settings_addr)?;
-    let path = p.read_string(settings_addr + COMMON_OFFSET.path)?;
-
-    // Attempt to construct a absolute path from executable path
-    if path == "Songs" {
-        if let Some(executable_dir) = &p.executable_dir {
-            let path = executable_dir.clone().join("Songs");
-
-            return Ok(path);
+    let path = match read_path_folder(p, state) {
+        Ok(path) if !path.is_empty() => path,
+        result => {
+            // keep the memory error, it's the one worth reporting
+            return match OsuConfig::from_process(p, state, None) {
+                Ok(config) => Ok(resolve_path(p, state, config.beatmap_directory())),
+                Err(err) => Err(result.err().unwrap_or(err)),
+            };
         }
-    }
+    };
 
-    Ok(PathBuf::from(path))
+    Ok(resolve_path(p, state, &path))
+}
+
+fn read_path_folder(p: &Process, state: &mut State) -> Result<String, Error> {
+    let settings_ptr = p.read_i32(state.addresses.settings + COMMON_OFFSET.settings_ptr)?;
+    let settings_addr = p.read_i32(settings_ptr + COMMON_OFFSET.settings_addr)?;
+    Ok(p.read_string(settings_addr + COMMON_OFFSET.path)?)
 }
 
 pub fn menu_mods_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
@@ -42,6 +53,52 @@ pub fn playtime_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
     Ok(p.read_i32(state.addresses.playtime + COMMON_OFFSET.ig_time)?)
 }
 
+/// Whether a replay is being watched, stays set on the result screen of the
+/// replay
+pub fn is_watching_replay(p: &Process, state: &mut State) -> Result<bool, Error> {
+    let can_run_slowly = optional_address(state.addresses.can_run_slowly, "can_run_slowly")?;
+    let replay_mode_addr = p.read_i32(can_run_slowly + COMMON_OFFSET.replay_mode)?;
+    Ok(p.read_u8(replay_mode_addr)? != 0)
+}
+
+/// Address of the spectated user, 0 when not spectating
+pub fn spectating_user_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let spectating_user = optional_address(state.addresses.spectating_user, "spectating_user")?;
+    let spectating_user_ptr = p.read_i32(spectating_user + COMMON_OFFSET.spectating_user)?;
+    Ok(p.read_i32(spectating_user_ptr)?)
+}
+
+pub fn is_spectating(p: &Process, state: &mut State) -> Result<bool, Error> {
+    Ok(spectating_user_addr(p, state)? != 0)
+}
+
+/// Name of the spectated player, `None` when not spectating
+pub fn spectating_username(p: &Process, state: &mut State) -> Result<Option<String>, Error> {
+    let user_addr = spectating_user_addr(p, state)?;
+    if user_addr == 0 {
+        return Ok(None);
+    }
+    Ok(Some(p.read_string(
+        user_addr + COMMON_OFFSET.spectating_username,
+    )?))
+}
+
+/// Kind of the play shown, `mods` are the ones of the play
+///
+/// Without the optional signatures, replays and spectating are reported as
+/// plays instead of failing
+pub fn play_kind(p: &Process, state: &mut State, mods: u32) -> Result<PlayKind, Error> {
+    let or_false = |result: Result<bool, Error>| match result {
+        Err(Error::NotAvailable(_)) => Ok(false),
+        result => result,
+    };
+    Ok(PlayKind::new(
+        mods,
+        or_false(is_spectating(p, state))?,
+        or_false(is_watching_replay(p, state))?,
+    ))
+}
+
 generate_offset_getter! {
     game_state: GameState = read_u32(0, status_addr);
     menu_game_mode: u32 = read_u32(0, menu_mods_addr); // TODO: use GameModsLegacy
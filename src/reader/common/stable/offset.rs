@@ -5,6 +5,9 @@ pub struct CommonOffset {
     pub status: i32,
     pub mods_ptr: i32,
     pub ig_time: i32,
+    pub replay_mode: i32,
+    pub spectating_user: i32,
+    pub spectating_username: i32,
 }
 
 pub(crate) const COMMON_OFFSET: CommonOffset = CommonOffset {
@@ -14,4 +17,7 @@ pub(crate) const COMMON_OFFSET: CommonOffset = CommonOffset {
     status: 0x4,
     mods_ptr: 0x9,
     ig_time: 0x5,
+    replay_mode: 0x46,
+    spectating_user: 0x2,
+    spectating_username: 0x30,
 };
@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+
+use crate::reader::common::{GameMode, PlayKind};
 use crate::reader::structs::Hit;
 
 #[derive(Debug, Clone)]
@@ -11,4 +14,221 @@ pub struct GameplayInfo {
     pub ig_time: i32,
     pub retries: i32,
     pub hits: Hit,
+    pub kind: PlayKind,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyState {
+    pub pressed: bool,
+    pub count: i32,
+}
+
+/// Keys of the in-game key overlay
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyOverlayState {
+    pub k1: KeyState,
+    pub k2: KeyState,
+    pub m1: KeyState,
+    pub m2: KeyState,
+}
+
+/// Replay frame as stored in memory during gameplay, `time` is absolute
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameplayReplayFrame {
+    pub time: i32,
+    pub x: f32,
+    pub y: f32,
+    pub keys: u32,
+}
+
+/// Judgement given to a hit depending on its timing error, from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HitJudgement {
+    /// mania MAX/rainbow 300
+    Perfect,
+    Great,
+    /// mania 200
+    Good,
+    Ok,
+    Meh,
+}
+
+/// Half hit windows in ms, `None` when the mode doesn't have the judgement
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HitWindows {
+    pub perfect: Option<f64>,
+    pub great: f64,
+    pub good: Option<f64>,
+    pub ok: f64,
+    pub meh: Option<f64>,
+}
+
+impl HitWindows {
+    /// Hit windows of osu!stable for the given overall difficulty, see
+    /// [`HitWindows::with_mods`] for EZ/HR. Catch has no timing judgements
+    pub fn new(mode: GameMode, od: f64) -> Option<Self> {
+        match mode {
+            GameMode::Osu => Some(Self {
+                perfect: None,
+                great: 80.0 - 6.0 * od,
+                good: None,
+                ok: 140.0 - 8.0 * od,
+                meh: Some(200.0 - 10.0 * od),
+            }),
+            GameMode::Taiko => Some(Self {
+                perfect: None,
+                great: 50.0 - 3.0 * od,
+                good: None,
+                ok: if od <= 5.0 {
+                    120.0 - 8.0 * od
+                } else {
+                    110.0 - 6.0 * od
+                },
+                meh: None,
+            }),
+            GameMode::Mania => Some(Self {
+                perfect: Some(16.0),
+                great: 64.0 - 3.0 * od,
+                good: Some(97.0 - 3.0 * od),
+                ok: 127.0 - 3.0 * od,
+                meh: Some(151.0 - 3.0 * od),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Hit windows with EZ/HR applied, to the overall difficulty in osu! and
+    /// taiko and to the windows themselves in mania
+    pub fn with_mods(mode: GameMode, od: f64, mods: u32) -> Option<Self> {
+        const EASY: u32 = 1 << 1;
+        const HARD_ROCK: u32 = 1 << 4;
+
+        if mode == GameMode::Mania {
+            let scale = if mods & HARD_ROCK != 0 {
+                1.0 / 1.4
+            } else if mods & EASY != 0 {
+                1.4
+            } else {
+                1.0
+            };
+            let windows = Self::new(mode, od)?;
+            return Some(Self {
+                perfect: windows.perfect.map(|w| w * scale),
+                great: windows.great * scale,
+                good: windows.good.map(|w| w * scale),
+                ok: windows.ok * scale,
+                meh: windows.meh.map(|w| w * scale),
+            });
+        }
+
+        let od = if mods & HARD_ROCK != 0 {
+            (od * 1.4).min(10.0)
+        } else if mods & EASY != 0 {
+            od * 0.5
+        } else {
+            od
+        };
+        Self::new(mode, od)
+    }
+
+    pub fn judge(&self, error: i32) -> Option<HitJudgement> {
+        let error = (error as f64).abs();
+        let windows = [
+            (self.perfect, HitJudgement::Perfect),
+            (Some(self.great), HitJudgement::Great),
+            (self.good, HitJudgement::Good),
+            (Some(self.ok), HitJudgement::Ok),
+            (self.meh, HitJudgement::Meh),
+        ];
+        windows
+            .into_iter()
+            .find(|(window, _)| window.is_some_and(|w| error <= w))
+            .map(|(_, judgement)| judgement)
+    }
+
+    /// Histogram of the hit errors of each judgement, errors outside of every
+    /// window are ignored
+    pub fn histograms(&self, errors: &[i32], bucket_size: i32) -> Vec<HitErrorHistogram> {
+        let bucket_size = bucket_size.max(1);
+        let mut histograms: Vec<HitErrorHistogram> = Vec::new();
+        for &error in errors {
+            let Some(judgement) = self.judge(error) else {
+                continue;
+            };
+            let idx = match histograms.iter().position(|h| h.judgement == judgement) {
+                Some(idx) => idx,
+                None => {
+                    histograms.push(HitErrorHistogram {
+                        judgement,
+                        bucket_size,
+                        count: 0,
+                        buckets: BTreeMap::new(),
+                    });
+                    histograms.len() - 1
+                }
+            };
+            let histogram = &mut histograms[idx];
+            histogram.count += 1;
+            *histogram
+                .buckets
+                .entry(error.div_euclid(bucket_size) * bucket_size)
+                .or_default() += 1;
+        }
+        histograms.sort_by_key(|h| h.judgement);
+        histograms
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HitErrorHistogram {
+    pub judgement: HitJudgement,
+    pub bucket_size: i32,
+    pub count: usize,
+    /// hit count by bucket start in ms, negative is early
+    pub buckets: BTreeMap<i32, usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct HitErrorStats {
+    pub count: usize,
+    /// mean hit error in ms, negative is early
+    pub mean: f64,
+    /// mean of the early hits only
+    pub early_mean: Option<f64>,
+    /// mean of the late hits only
+    pub late_mean: Option<f64>,
+    pub unstable_rate: f64,
+}
+
+impl HitErrorStats {
+    /// Errors are in game time, `clock_rate` scales them back to real time
+    /// like osu! does for the unstable rate with DT/HT
+    pub fn new(errors: &[i32], clock_rate: f64) -> Self {
+        if errors.is_empty() {
+            return Self::default();
+        }
+        let count = errors.len();
+        let mean = errors.iter().map(|&e| e as f64).sum::<f64>() / count as f64;
+        let variance = errors
+            .iter()
+            .map(|&e| (e as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count as f64;
+
+        let mean_of = |filter: fn(&i32) -> bool| {
+            let (sum, n) = errors
+                .iter()
+                .filter(|e| filter(e))
+                .fold((0.0, 0), |(sum, n), &e| (sum + e as f64, n + 1));
+            (n > 0).then(|| sum / n as f64)
+        };
+
+        Self {
+            count,
+            mean,
+            early_mean: mean_of(|e| *e < 0),
+            late_mean: mean_of(|e| *e >= 0),
+            unstable_rate: variance.sqrt() * 10.0 / clock_rate,
+        }
+    }
 }
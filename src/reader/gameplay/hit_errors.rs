@@ -0,0 +1,56 @@
+use crate::reader::gameplay::common::{HitErrorHistogram, HitErrorStats, HitWindows};
+use crate::reader::gameplay::stable::memory::{hit_error_count, hit_errors, retries};
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Keeps the hit errors of the current play, only reading the new ones on
+/// each update
+#[derive(Debug, Clone, Default)]
+pub struct HitErrorTracker {
+    errors: Vec<i32>,
+    retries: Option<i32>,
+}
+
+impl HitErrorTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the hit errors added since the last update and returns them,
+    /// starts over when the play is restarted
+    pub fn update(&mut self, p: &Process, state: &mut State) -> Result<&[i32], Error> {
+        let retries = retries(p, state)?;
+        let count = hit_error_count(p, state)?;
+        if self.retries != Some(retries) || count < self.errors.len() {
+            self.reset();
+            self.retries = Some(retries);
+        }
+
+        let start = self.errors.len();
+        self.errors.extend(hit_errors(p, state, start)?);
+        Ok(&self.errors[start..])
+    }
+
+    pub fn reset(&mut self) {
+        self.errors.clear();
+        self.retries = None;
+    }
+
+    /// Every hit error of the current play in ms, negative is early
+    pub fn errors(&self) -> &[i32] {
+        &self.errors
+    }
+
+    pub fn stats(&self, clock_rate: f64) -> HitErrorStats {
+        HitErrorStats::new(&self.errors, clock_rate)
+    }
+
+    pub fn unstable_rate(&self, clock_rate: f64) -> f64 {
+        self.stats(clock_rate).unstable_rate
+    }
+
+    pub fn histograms(&self, windows: &HitWindows, bucket_size: i32) -> Vec<HitErrorHistogram> {
+        windows.histograms(&self.errors, bucket_size)
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use crate::reader::gameplay::common::{KeyOverlayState, KeyState};
+use crate::reader::gameplay::stable::memory::{game_time, key_overlay};
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Default time window used for KPS/BPM
+pub const DEFAULT_KPS_WINDOW: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    K1,
+    K2,
+    M1,
+    M2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyEventKind {
+    Press,
+    Release,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyEvent {
+    pub key: Key,
+    pub kind: KeyEventKind,
+    /// game time in ms when the change was read
+    pub game_time: i32,
+    pub at: Instant,
+}
+
+/// Turns successive key overlay reads into press/release events
+///
+/// Presses happening between two updates are still counted thanks to the
+/// key counters, but their timestamps are the ones of the update
+#[derive(Debug, Clone)]
+pub struct KeyOverlay {
+    last: Option<KeyOverlayState>,
+    presses: VecDeque<Instant>,
+    window: Duration,
+}
+
+impl Default for KeyOverlay {
+    fn default() -> Self {
+        Self::new(DEFAULT_KPS_WINDOW)
+    }
+}
+
+impl KeyOverlay {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            last: None,
+            presses: VecDeque::new(),
+            window,
+        }
+    }
+
+    pub fn update(&mut self, p: &Process, state: &mut State) -> Result<Vec<KeyEvent>, Error> {
+        let keys = key_overlay(p, state)?;
+        let game_time = game_time(p, state)?;
+        Ok(self.push(keys, game_time, Instant::now()))
+    }
+
+    /// Feeds a key overlay read, see [`KeyOverlay::update`]
+    pub fn push(&mut self, keys: KeyOverlayState, game_time: i32, at: Instant) -> Vec<KeyEvent> {
+        let mut events = Vec::new();
+        if let Some(last) = self.last {
+            let pairs = [
+                (Key::K1, last.k1, keys.k1),
+                (Key::K2, last.k2, keys.k2),
+                (Key::M1, last.m1, keys.m1),
+                (Key::M2, last.m2, keys.m2),
+            ];
+            // counters going down means the play was restarted
+            if pairs.iter().all(|(_, old, new)| new.count >= old.count) {
+                for (key, old, new) in pairs {
+                    key_events(old, new, &mut |kind| {
+                        events.push(KeyEvent {
+                            key,
+                            kind,
+                            game_time,
+                            at,
+                        })
+                    });
+                }
+            } else {
+                self.presses.clear();
+            }
+        }
+        self.last = Some(keys);
+
+        self.presses.extend(
+            events
+                .iter()
+                .filter(|e| e.kind == KeyEventKind::Press)
+                .map(|e| e.at),
+        );
+        self.prune(at);
+        events
+    }
+
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.presses.clear();
+    }
+
+    /// Last read state of the keys
+    pub fn keys(&self) -> Option<KeyOverlayState> {
+        self.last
+    }
+
+    /// Key presses per second over the time window ending at the last read,
+    /// presses older than the window are dropped on each read
+    pub fn kps(&self) -> f64 {
+        self.presses.len() as f64 / self.window.as_secs_f64()
+    }
+
+    /// Streaming BPM, one press per 1/4 beat
+    pub fn bpm(&self) -> f64 {
+        self.kps() * 60.0 / 4.0
+    }
+
+    fn prune(&mut self, now: Instant) {
+        while self
+            .presses
+            .front()
+            .is_some_and(|&at| now.duration_since(at) > self.window)
+        {
+            self.presses.pop_front();
+        }
+    }
+}
+
+fn key_events(old: KeyState, new: KeyState, emit: &mut impl FnMut(KeyEventKind)) {
+    let presses = new.count - old.count;
+    if presses == 0 {
+        if old.pressed && !new.pressed {
+            emit(KeyEventKind::Release);
+        }
+        return;
+    }
+
+    if old.pressed {
+        emit(KeyEventKind::Release);
+    }
+    for i in 0..presses {
+        emit(KeyEventKind::Press);
+        if i < presses - 1 || !new.pressed {
+            emit(KeyEventKind::Release);
+        }
+    }
+}
@@ -1,9 +1,15 @@
 pub mod common;
+pub mod hit_errors;
+pub mod key_overlay;
+pub mod session;
 pub mod stable;
 
 use crate::impl_osu_accessor;
 use crate::reader::common::OsuClientKind;
+use crate::reader::common::{GameMode, Grade, PlayKind};
 use crate::reader::gameplay::common::GameplayInfo;
+use crate::reader::gameplay::common::GameplayReplayFrame;
+use crate::reader::gameplay::common::KeyOverlayState;
 use crate::reader::structs::Hit;
 use crate::reader::structs::State;
 use crate::Error;
@@ -39,6 +45,31 @@ impl<'a> GameplayReader<'a> {
         fn hits_geki() -> i16 => stable::memory::hits_geki,
         fn hits_katu() -> i16 => stable::memory::hits_katu,
         fn info() -> GameplayInfo => stable::memory::info,
+        fn mode() -> GameMode => stable::memory::mode,
+        fn accuracy() -> f64 => stable::memory::accuracy,
+        fn grade() -> Grade => stable::memory::grade,
+        fn play_kind() -> PlayKind => stable::memory::play_kind,
+        fn projected_score() -> f64 => stable::memory::projected_score,
+        fn replay_frame_count() -> usize => stable::memory::replay_frame_count,
+        fn hit_error_count() -> usize => stable::memory::hit_error_count,
+        fn key_overlay() -> KeyOverlayState => stable::memory::key_overlay,
+    }
 
+    pub fn replay_frames(&mut self, start: usize) -> Result<Vec<GameplayReplayFrame>, Error> {
+        match self.osu_type {
+            OsuClientKind::Stable => stable::memory::replay_frames(self.process, self.state, start),
+            _ => Err(Error::Unsupported(
+                "Unsupported osu type for now".to_string(),
+            )),
+        }
+    }
+
+    pub fn hit_errors(&mut self, start: usize) -> Result<Vec<i32>, Error> {
+        match self.osu_type {
+            OsuClientKind::Stable => stable::memory::hit_errors(self.process, self.state, start),
+            _ => Err(Error::Unsupported(
+                "Unsupported osu type for now".to_string(),
+            )),
+        }
     }
 }
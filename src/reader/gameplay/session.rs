@@ -0,0 +1,256 @@
+use std::time::{Duration, Instant};
+
+use crate::reader::common::stable::memory::game_state;
+use crate::reader::common::{GameMode, GameState};
+use crate::reader::gameplay::common::GameplayInfo;
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Game time not moving for this long while playing is a pause
+pub const DEFAULT_PAUSE_THRESHOLD: Duration = Duration::from_millis(150);
+
+const MOD_NO_FAIL: u32 = 1;
+const MOD_EASY: u32 = 2;
+
+/// Times HP can reach 0 with EZ before failing
+const EASY_EXTRA_LIVES: u32 = 2;
+
+/// What is read from memory on each update while playing
+#[derive(Debug, Clone)]
+pub struct PlaySnapshot {
+    pub beatmap_md5: String,
+    pub mode: GameMode,
+    pub info: GameplayInfo,
+}
+
+/// An attempt that ended, with the last gameplay values read
+#[derive(Debug, Clone)]
+pub struct PlayResult {
+    pub beatmap_md5: String,
+    pub mode: GameMode,
+    pub info: GameplayInfo,
+    /// real time spent playing, pauses excluded
+    pub play_time: Duration,
+}
+
+/// Each attempt emits `Started` then ends with exactly one of `Failed`,
+/// `Retried`, `Quit` or `Completed`
+#[derive(Debug, Clone)]
+pub enum PlayEvent {
+    Started { beatmap_md5: String, mode: GameMode },
+    Paused { game_time: i32 },
+    Resumed { game_time: i32 },
+    Failed(Box<PlayResult>),
+    Retried(Box<PlayResult>),
+    Quit(Box<PlayResult>),
+    Completed(Box<PlayResult>),
+}
+
+#[derive(Debug, Clone)]
+struct Attempt {
+    beatmap_md5: String,
+    mode: GameMode,
+    last: GameplayInfo,
+    started_at: Instant,
+    /// when the game time last moved
+    time_moved_at: Instant,
+    time_started: bool,
+    paused_since: Option<Instant>,
+    paused_total: Duration,
+    /// extra lives of EZ used so far
+    lives_used: u32,
+}
+
+impl Attempt {
+    fn new(snapshot: PlaySnapshot, now: Instant) -> Self {
+        Self {
+            beatmap_md5: snapshot.beatmap_md5,
+            mode: snapshot.mode,
+            last: snapshot.info,
+            started_at: now,
+            time_moved_at: now,
+            time_started: false,
+            paused_since: None,
+            paused_total: Duration::ZERO,
+            lives_used: 0,
+        }
+    }
+
+    /// Whether the read means the attempt failed
+    ///
+    /// The taiko HP bar starts empty and a taiko fail is only decided at the
+    /// end of the map, so taiko attempts never fail here
+    fn is_failed(&mut self, info: &GameplayInfo) -> bool {
+        if self.mode == GameMode::Taiko || info.mods & MOD_NO_FAIL != 0 || !self.time_started {
+            return false;
+        }
+        // EZ refills HP instead of failing, count when it reaches 0
+        let reached_zero = info.hp <= 0.0 && self.last.hp > 0.0;
+        if info.mods & MOD_EASY != 0 {
+            if reached_zero && self.lives_used < EASY_EXTRA_LIVES {
+                self.lives_used += 1;
+                return false;
+            }
+            return reached_zero;
+        }
+        info.hp <= 0.0
+    }
+
+    fn finish(self, now: Instant) -> Box<PlayResult> {
+        let paused = self.paused_total + self.paused_since.map_or(Duration::ZERO, |p| now - p);
+        Box::new(PlayResult {
+            beatmap_md5: self.beatmap_md5,
+            mode: self.mode,
+            info: self.last,
+            play_time: (now - self.started_at).saturating_sub(paused),
+        })
+    }
+}
+
+/// Turns successive gameplay reads into play events
+///
+/// Call [`PlaySession::update`] regularly (e.g. every 50ms), pauses are
+/// detected from the game time not moving for [`DEFAULT_PAUSE_THRESHOLD`]
+///
+/// Fails are detected from HP, taiko fails are decided at the end of the map
+/// so they are never reported as `Failed`
+#[derive(Debug, Clone)]
+pub struct PlaySession {
+    attempt: Option<Attempt>,
+    /// beatmap md5 and retries of the failed attempt, waiting for a retry or
+    /// to leave the play
+    failed: Option<(String, i32)>,
+    pause_threshold: Duration,
+}
+
+impl Default for PlaySession {
+    fn default() -> Self {
+        Self::new(DEFAULT_PAUSE_THRESHOLD)
+    }
+}
+
+impl PlaySession {
+    pub fn new(pause_threshold: Duration) -> Self {
+        Self {
+            attempt: None,
+            failed: None,
+            pause_threshold,
+        }
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.attempt.is_some()
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.attempt
+            .as_ref()
+            .is_some_and(|a| a.paused_since.is_some())
+    }
+
+    pub fn update(&mut self, p: &Process, state: &mut State) -> Result<Vec<PlayEvent>, Error> {
+        let game_state = game_state(p, state)?;
+        let snapshot = if game_state == GameState::Playing {
+            use crate::reader::gameplay::stable::memory as gameplay;
+            Some(PlaySnapshot {
+                beatmap_md5: crate::reader::beatmap::stable::memory::md5(p, state)?,
+                mode: gameplay::mode(p, state)?,
+                info: gameplay::info(p, state)?,
+            })
+        } else {
+            None
+        };
+        Ok(self.push(game_state, snapshot, Instant::now()))
+    }
+
+    /// Feeds a read, `snapshot` is only needed while playing,
+    /// see [`PlaySession::update`]
+    pub fn push(
+        &mut self,
+        game_state: GameState,
+        snapshot: Option<PlaySnapshot>,
+        now: Instant,
+    ) -> Vec<PlayEvent> {
+        let mut events = Vec::new();
+        let snapshot = match (game_state, snapshot) {
+            (GameState::Playing, Some(snapshot)) => snapshot,
+            (GameState::Playing, None) => return events,
+            _ => {
+                self.failed = None;
+                if let Some(attempt) = self.attempt.take() {
+                    let result = attempt.finish(now);
+                    events.push(if game_state.is_result_screen() {
+                        PlayEvent::Completed(result)
+                    } else {
+                        PlayEvent::Quit(result)
+                    });
+                }
+                return events;
+            }
+        };
+
+        let is_restart = |md5: &str, retries: i32| {
+            retries != snapshot.info.retries || md5 != snapshot.beatmap_md5
+        };
+        if let Some((md5, retries)) = &self.failed {
+            // nothing to track on the fail screen until a retry
+            if !is_restart(md5, *retries) {
+                return events;
+            }
+            self.failed = None;
+        }
+        if self
+            .attempt
+            .as_ref()
+            .is_some_and(|a| is_restart(&a.beatmap_md5, a.last.retries))
+        {
+            if let Some(attempt) = self.attempt.take() {
+                events.push(PlayEvent::Retried(attempt.finish(now)));
+            }
+        }
+
+        let Some(attempt) = self.attempt.as_mut() else {
+            events.push(PlayEvent::Started {
+                beatmap_md5: snapshot.beatmap_md5.clone(),
+                mode: snapshot.mode,
+            });
+            self.attempt = Some(Attempt::new(snapshot, now));
+            return events;
+        };
+
+        let info = snapshot.info;
+        let time_moved = info.ig_time != attempt.last.ig_time;
+        if time_moved {
+            attempt.time_started = true;
+            attempt.time_moved_at = now;
+            if let Some(paused_since) = attempt.paused_since.take() {
+                attempt.paused_total += now - paused_since;
+                events.push(PlayEvent::Resumed {
+                    game_time: info.ig_time,
+                });
+            }
+        }
+
+        let failed = attempt.is_failed(&info);
+        attempt.last = info;
+        if failed {
+            if let Some(attempt) = self.attempt.take() {
+                self.failed = Some((attempt.beatmap_md5.clone(), attempt.last.retries));
+                events.push(PlayEvent::Failed(attempt.finish(now)));
+            }
+            return events;
+        }
+
+        if attempt.time_started
+            && attempt.paused_since.is_none()
+            && now - attempt.time_moved_at >= self.pause_threshold
+        {
+            attempt.paused_since = Some(attempt.time_moved_at);
+            events.push(PlayEvent::Paused {
+                game_time: attempt.last.ig_time,
+            });
+        }
+        events
+    }
+}
@@ -1,13 +1,19 @@
 use crate::reader::common::stable::memory::check_game_state;
-use crate::reader::common::GameState;
-use crate::reader::gameplay::common::GameplayInfo;
+use crate::reader::common::stable::memory::play_kind as common_play_kind;
+use crate::reader::common::{GameMode, GameState, Grade, PlayKind};
+use crate::reader::gameplay::common::{
+    GameplayInfo, GameplayReplayFrame, KeyOverlayState, KeyState,
+};
 use crate::reader::gameplay::stable::offset::GAMEPLAY_OFFSET;
 use crate::reader::structs::Hit;
 use crate::reader::structs::State;
+use crate::scoring::{calculate_accuracy, calculate_grade, calculate_projected_score};
 use crate::Error;
 use crate::{
     generate_offset_getter,
-    reader::helpers::{read_f64, read_i16, read_i32, read_string, read_u64},
+    reader::helpers::{
+        read_f64, read_i16, read_i32, read_list, read_list_i32, read_string, read_u64,
+    },
 };
 use std::mem::size_of;
 use rosu_mem::process::{Process, ProcessTraits};
@@ -39,12 +45,15 @@ generate_offset_getter! {
     max_combo: i16 = read_i16(GAMEPLAY_OFFSET.max_combo, score_base);
     hp: f64 = read_f64(GAMEPLAY_OFFSET.hp, hp_base);
     username: String = read_string(GAMEPLAY_OFFSET.username, score_base);
+    mode: GameMode = read_i32(GAMEPLAY_OFFSET.mode, score_base);
     hits_300: i16 = read_i16(GAMEPLAY_OFFSET.hits._300, score_base);
     hits_100: i16 = read_i16(GAMEPLAY_OFFSET.hits._100, score_base);
     hits_50: i16 = read_i16(GAMEPLAY_OFFSET.hits._50, score_base);
     hits_miss: i16 = read_i16(GAMEPLAY_OFFSET.hits._miss, score_base);
     hits_geki: i16 = read_i16(GAMEPLAY_OFFSET.hits._geki, score_base);
     hits_katu: i16 = read_i16(GAMEPLAY_OFFSET.hits._katu, score_base);
+    replay_addr: i32 = read_i32(GAMEPLAY_OFFSET.replay, score_base);
+    hit_errors_addr: i32 = read_i32(GAMEPLAY_OFFSET.hit_errors, score_base);
 }
 
 /// this is a wrapper to not confuse people it could be deleted in the future
@@ -118,5 +127,128 @@ pub fn info(p: &Process, state: &mut State) -> Result<GameplayInfo, Error> {
         ig_time: game_time(p, state)?, // different base
         retries: retries(p, state)?,   // different base
         hits,
+        kind: common_play_kind(p, state, mods)?,
+    })
+}
+
+/// Whether the play is the user's own, a replay, spectated or autoplay
+pub fn play_kind(p: &Process, state: &mut State) -> Result<PlayKind, Error> {
+    let mods = mods(p, state)?;
+    common_play_kind(p, state, mods)
+}
+
+pub fn accuracy(p: &Process, state: &mut State) -> Result<f64, Error> {
+    calculate_accuracy(&mode(p, state)?, &hits(p, state)?)
+}
+
+pub fn grade(p: &Process, state: &mut State) -> Result<Grade, Error> {
+    calculate_grade(&mode(p, state)?, &hits(p, state)?, mods(p, state)?)
+}
+
+/// Score V2 projected to the end of the beatmap, see [`calculate_projected_score`]
+///
+/// The difficulty of the beatmap gives its max combo and judged objects, it is
+/// cached with the beatmap so it is only calculated once per mods
+pub fn projected_score(p: &Process, state: &mut State) -> Result<f64, Error> {
+    let mode = mode(p, state)?;
+    let mods = mods(p, state)?;
+    let difficulty = crate::reader::beatmap::stable::file::cached_difficulty(p, state, mode, mods)?;
+    calculate_projected_score(
+        &mode,
+        &hits(p, state)?,
+        max_combo(p, state)?,
+        difficulty.max_combo,
+        difficulty.judged_objects() as usize,
+        mods,
+    )
+}
+
+/// Address of the `List<bReplayFrame>` of the score
+///
+/// The offset is not checked on every osu! build, so the list is checked to
+/// look like a .NET list (its size fits in its array) before being read
+fn checked_replay_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let replay_addr = replay_addr(p, state)?;
+    if replay_addr == 0 {
+        return Err(Error::NotAvailable("No replay frames".to_string()));
+    }
+    let (items, size) = read_list(p, replay_addr)?;
+    // `items` points past the array header, its length is right before
+    let capacity = p.read_i32(items - 0x4)?;
+    if capacity < 0 || size > capacity as usize {
+        return Err(Error::NotAvailable(format!(
+            "Replay frames list looks invalid ({size} items, capacity {capacity}), \
+             the replay offset may be wrong for this osu! build"
+        )));
+    }
+    Ok(replay_addr)
+}
+
+pub fn replay_frame_count(p: &Process, state: &mut State) -> Result<usize, Error> {
+    let replay_addr = checked_replay_addr(p, state)?;
+    Ok(read_list(p, replay_addr)?.1)
+}
+
+/// Reads the replay frames recorded so far, skipping the first `start` ones
+/// so it can be called incrementally during gameplay
+pub fn replay_frames(
+    p: &Process,
+    state: &mut State,
+    start: usize,
+) -> Result<Vec<GameplayReplayFrame>, Error> {
+    let replay_addr = checked_replay_addr(p, state)?;
+    let mut frames = Vec::new();
+    for frame_addr in read_list_i32(p, replay_addr, start)? {
+        // x, y, keys and time are next to each other, read them in one memory operation
+        let mut buffer = [0u8; size_of::<i32>() * 4];
+        p.read(
+            frame_addr + GAMEPLAY_OFFSET.replay_frame.x,
+            size_of::<i32>() * 4,
+            &mut buffer,
+        )?;
+        frames.push(GameplayReplayFrame {
+            x: f32::from_le_bytes(buffer[0..4].try_into().unwrap()),
+            y: f32::from_le_bytes(buffer[4..8].try_into().unwrap()),
+            keys: u32::from_le_bytes(buffer[8..12].try_into().unwrap()),
+            time: i32::from_le_bytes(buffer[12..16].try_into().unwrap()),
+        });
+    }
+    Ok(frames)
+}
+
+pub fn hit_error_count(p: &Process, state: &mut State) -> Result<usize, Error> {
+    let hit_errors_addr = hit_errors_addr(p, state)?;
+    Ok(read_list(p, hit_errors_addr)?.1)
+}
+
+/// Reads the hit errors in ms (negative is early) recorded so far, skipping
+/// the first `start` ones so it can be called incrementally during gameplay
+pub fn hit_errors(p: &Process, state: &mut State, start: usize) -> Result<Vec<i32>, Error> {
+    let hit_errors_addr = hit_errors_addr(p, state)?;
+    read_list_i32(p, hit_errors_addr, start)
+}
+
+pub fn key_overlay(p: &Process, state: &mut State) -> Result<KeyOverlayState, Error> {
+    let offset = &GAMEPLAY_OFFSET.key_overlay;
+    let ruleset_addr = ruleset_addr(p, state)?;
+    let key_overlay_addr = p.read_i32(ruleset_addr + offset.ptr)?;
+    if key_overlay_addr == 0 {
+        return Err(Error::NotAvailable("Key overlay is disabled".to_string()));
+    }
+    let keys_addr = p.read_i32(p.read_i32(key_overlay_addr + offset.container)? + offset.keys)?;
+
+    let read_key = |key: i32| -> Result<KeyState, Error> {
+        let key_addr = p.read_i32(keys_addr + key)?;
+        Ok(KeyState {
+            pressed: p.read_u8(key_addr + offset.pressed)? != 0,
+            count: p.read_i32(key_addr + offset.count)?,
+        })
+    };
+
+    Ok(KeyOverlayState {
+        k1: read_key(offset.k1)?,
+        k2: read_key(offset.k2)?,
+        m1: read_key(offset.m1)?,
+        m2: read_key(offset.m2)?,
     })
 }
@@ -14,7 +14,12 @@ pub struct GameplayOffset {
     pub hp: i32,
     pub username: i32,
     pub retries: i32,
+    pub mode: i32,
+    pub replay: i32,
+    pub hit_errors: i32,
     pub hits: GameplayHitsOffset,
+    pub replay_frame: ReplayFrameOffset,
+    pub key_overlay: KeyOverlayOffset,
 }
 
 pub const GAMEPLAY_OFFSET: GameplayOffset = GameplayOffset {
@@ -33,7 +38,12 @@ pub const GAMEPLAY_OFFSET: GameplayOffset = GameplayOffset {
     hp: 0x1C,
     username: 0x28,
     retries: 0x8,
+    mode: 0x64,
+    replay: 0x34,     // List<bReplayFrame> on the score, unverified, checked when read
+    hit_errors: 0x38, // List<int>
     hits: GAMEPLAY_HITS_OFFSET,
+    replay_frame: REPLAY_FRAME_OFFSET,
+    key_overlay: KEY_OVERLAY_OFFSET,
 };
 
 pub struct GameplayHitsOffset {
@@ -53,3 +63,41 @@ pub const GAMEPLAY_HITS_OFFSET: GameplayHitsOffset = GameplayHitsOffset {
     _katu: 0x90, // 144
     _miss: 0x92  // 146
 };
+
+pub struct ReplayFrameOffset {
+    pub x: i32,
+    pub y: i32,
+    pub keys: i32,
+    pub time: i32,
+}
+
+pub const REPLAY_FRAME_OFFSET: ReplayFrameOffset = ReplayFrameOffset {
+    x: 0x4,
+    y: 0x8,
+    keys: 0xC,
+    time: 0x10,
+};
+
+pub struct KeyOverlayOffset {
+    pub ptr: i32,
+    pub container: i32,
+    pub keys: i32,
+    pub k1: i32,
+    pub k2: i32,
+    pub m1: i32,
+    pub m2: i32,
+    pub pressed: i32,
+    pub count: i32,
+}
+
+pub const KEY_OVERLAY_OFFSET: KeyOverlayOffset = KeyOverlayOffset {
+    ptr: 0xB0, // from the ruleset, null when the key overlay is disabled
+    container: 0x4,
+    keys: 0x4,
+    k1: 0x8,
+    k2: 0xC,
+    m1: 0x10,
+    m2: 0x14,
+    pressed: 0x1C,
+    count: 0x14,
+};
@@ -12,7 +12,7 @@ use rosu_mem::process::{Process, ProcessTraits};
 /// 
 /// # Syntax
 /// 
-/// ```rust
+/// ```text
 /// generate_reader_fn! {
 ///     function_name, return_type, read_method
 /// }
@@ -26,11 +26,16 @@ use rosu_mem::process::{Process, ProcessTraits};
 /// 
 /// # Examples
 /// 
-/// ```rust
-/// generate_reader_fn! {
-///     read_score, i32, read_i32
-/// }
+/// The macro is crate-private, `generate_reader_fn! { read_i32, i32, read_i32 }`
+/// generates:
 /// 
+/// ```text
+/// pub(crate) fn read_i32(
+///     p: &Process,
+///     state: &mut State,
+///     offset: i32,
+///     get_base_addr: fn(&Process, &mut State) -> Result<i32, Error>,
+/// ) -> Result<i32, Error>
 /// ```
 macro_rules! generate_reader_fn {
     (
@@ -55,7 +60,7 @@ macro_rules! generate_reader_fn {
 /// 
 /// # Syntax
 /// 
-/// ```rust
+/// ```text
 /// generate_offset_getter! {
 ///     function_name: return_type = read_method(offset, base_getter);
 ///     another_function: another_type = another_read_method(another_offset, another_base);
@@ -73,12 +78,29 @@ macro_rules! generate_reader_fn {
 /// 
 /// # Examples
 /// 
-/// ```rust
+/// ```
+/// use rosu_mem::process::{Process, ProcessTraits};
+/// use rosu_memory_lib::generate_offset_getter;
+/// use rosu_memory_lib::reader::structs::State;
+/// use rosu_memory_lib::Error;
+///
+/// fn score_base(p: &Process, state: &mut State) -> Result<i32, Error> {
+///     Ok(p.read_i32(state.addresses.rulesets - 0xB)?)
+/// }
+///
+/// fn read_i32(
+///     p: &Process,
+///     state: &mut State,
+///     offset: i32,
+///     get_base_addr: fn(&Process, &mut State) -> Result<i32, Error>,
+/// ) -> Result<i32, Error> {
+///     Ok(p.read_i32(get_base_addr(p, state)? + offset)?)
+/// }
+///
 /// generate_offset_getter! {
-///     score: i32 = read_i32(0x10, score_base),
-///     combo: i16 = read_i16(0x14, score_base),
-///     username: String = read_string(0x20, score_base),
-///     hp: f64 = read_f64(0x30, hp_base),
+///     score: i32 = read_i32(0x10, score_base);
+///     // the value read is converted with `From`
+///     combo: i64 = read_i32(0x14, score_base);
 /// }
 /// 
 /// // Generates functions like:
@@ -90,7 +112,7 @@ macro_rules! generate_reader_fn {
 /// # Generated Functions
 /// 
 /// Each definition generates a function with signature:
-/// ```rust
+/// ```text
 /// pub fn function_name(p: &Process, state: &mut State) -> Result<return_type, Error>
 /// ```
 /// 
@@ -119,7 +141,7 @@ macro_rules! generate_offset_getter {
 /// 
 /// # Syntax
 /// 
-/// ```rust
+/// ```text
 /// impl_osu_accessor! {
 ///     fn method_name() -> return_type => implementation_path,
 ///     fn another_method() -> another_type => another_implementation,
@@ -134,12 +156,24 @@ macro_rules! generate_offset_getter {
 /// 
 /// # Examples
 /// 
-/// ```rust
+/// ```
+/// use rosu_mem::process::Process;
+/// use rosu_memory_lib::impl_osu_accessor;
+/// use rosu_memory_lib::reader::common::stable::memory;
+/// use rosu_memory_lib::reader::common::{GameState, OsuClientKind};
+/// use rosu_memory_lib::reader::structs::State;
+/// use rosu_memory_lib::Error;
+///
+/// pub struct CommonReader<'a> {
+///     pub process: &'a Process,
+///     pub state: &'a mut State,
+///     pub osu_type: OsuClientKind,
+/// }
+///
 /// impl<'a> CommonReader<'a> {
 ///     impl_osu_accessor! {
-///         fn game_state() -> GameState => stable::memory::game_state,
-///         fn menu_game_mode() -> GameMode => stable::memory::menu_game_mode,
-///         fn path_folder() -> PathBuf => stable::memory::path_folder,
+///         fn game_state() -> GameState => memory::game_state,
+///         fn menu_game_mode() -> u32 => memory::menu_game_mode,
 ///     }
 /// }
 /// ```
@@ -147,7 +181,7 @@ macro_rules! generate_offset_getter {
 /// # Generated Methods
 /// 
 /// For each definition, generates a method like:
-/// ```rust
+/// ```text
 /// pub fn method_name(&mut self) -> Result<return_type, Error> {
 ///     match self.osu_type {
 ///         OsuClientKind::Stable => implementation_path(self.process, self.state),
@@ -188,51 +222,57 @@ macro_rules! impl_osu_accessor {
     };
 }
 
-// TODO : idk where to put this
-#[inline]
-pub fn calculate_accuracy(gamemode: &GameMode, hit: &Hit) -> Result<f64, Error> {
-    let acc = match gamemode {
-        GameMode::Osu => {
-            let total = (hit._300 + hit._100 + hit._50 + hit._miss) as f64;
-            if total == 0.0 {
-                return Ok(0.0);
-            }
-            let score = hit._300 as f64 * 6.0 + hit._100 as f64 * 2.0 + hit._50 as f64;
-            (score / (total * 6.0)) * 100.0
-        }
-        GameMode::Taiko => {
-            let total = (hit._300 + hit._100 + hit._50 + hit._miss) as f64;
-            if total == 0.0 {
-                return Ok(0.0);
-            }
-            let score = hit._300 as f64 * 2.0 + hit._100 as f64;
-            (score / (total * 2.0)) * 100.0
-        }
-        GameMode::Catch => {
-            let caught = (hit._300 + hit._100 + hit._50) as f64;
-            let total = (hit._300 + hit._100 + hit._50 + hit._katu + hit._miss) as f64;
-            if total == 0.0 {
-                return Ok(0.0);
-            }
-            (caught / total) * 100.0
-        }
-        GameMode::Mania => {
-            let total = (hit._geki + hit._300 + hit._katu + hit._100 + hit._50 + hit._miss) as f64;
-            if total == 0.0 {
-                return Ok(0.0);
-            }
-            let score = (hit._geki + hit._300) as f64 * 6.0
-                + hit._katu as f64 * 4.0
-                + hit._100 as f64 * 2.0
-                + hit._50 as f64;
-            (score / (total * 6.0)) * 100.0
-        }
-        _ => return Ok(0.0),
-    };
+/// Reads the first item address and the size of a .NET `List<T>`
+///
+/// The items array is at 0x4 and the size at 0xC, the items start after the
+/// array header and length
+pub(crate) fn read_list(p: &Process, list_addr: i32) -> Result<(i32, usize), Error> {
+    let items = p.read_i32(list_addr + 0x4)?;
+    let size = p.read_i32(list_addr + 0xC)?;
+    Ok((items + 0x8, size.max(0) as usize))
+}
 
-    Ok(acc)
+/// Reads the items of a .NET `List<int>`, or the object addresses when `T`
+/// is a class, skipping the first `start` items
+pub(crate) fn read_list_i32(p: &Process, list_addr: i32, start: usize) -> Result<Vec<i32>, Error> {
+    let (items, size) = read_list(p, list_addr)?;
+    if start >= size {
+        return Ok(Vec::new());
+    }
+    read_i32_items(p, items + (start * size_of::<i32>()) as i32, size - start)
 }
 
+/// Reads a .NET `int[]`, also works for arrays of objects as 32 bits pointers
+#[cfg(feature = "unverified-multiplayer")]
+pub(crate) fn read_array_i32(p: &Process, array_addr: i32) -> Result<Vec<i32>, Error> {
+    if array_addr == 0 {
+        return Ok(Vec::new());
+    }
+    let size = p.read_i32(array_addr + 0x4)?;
+    read_i32_items(p, array_addr + 0x8, size.max(0) as usize)
+}
+
+fn read_i32_items(p: &Process, addr: i32, count: usize) -> Result<Vec<i32>, Error> {
+    let mut buffer = vec![0u8; count * size_of::<i32>()];
+    p.read(addr, buffer.len(), &mut buffer)?;
+    Ok(buffer
+        .chunks_exact(size_of::<i32>())
+        .map(|c| i32::from_le_bytes(c.try_into().unwrap()))
+        .collect())
+}
+
+/// Accuracy in percent, 0 for an empty play or an unknown mode
+#[deprecated(
+    note = "use `scoring::calculate_accuracy`, it errors on unknown modes and gives 100 for an empty play"
+)]
+#[inline]
+pub fn calculate_accuracy(gamemode: &GameMode, hit: &Hit) -> Result<f64, Error> {
+    // keeps the results it had before `scoring`
+    match crate::scoring::Judgements::from_hits(gamemode, hit) {
+        Ok(judgements) if judgements.total() > 0 => Ok(judgements.accuracy()),
+        _ => Ok(0.0),
+    }
+}
 
 
 // Macro usage
@@ -0,0 +1,222 @@
+//! Every running osu! instance, e.g. a tournament manager and its clients
+use std::path::PathBuf;
+
+use crate::reader::structs::{State, StaticAddresses};
+use crate::Error;
+use rosu_mem::process::{Process, ProcessTraits};
+
+/// Finds every process matching `proc_name`, unlike `Process::initialize`
+/// which stops at the first one
+///
+/// Like rosu-mem, `proc_name` and `exclude` are matched with `contains` on
+/// the executable path, processes come in no particular order
+pub fn find_all(proc_name: &str, exclude: &[&str]) -> Result<Vec<Process>, Error> {
+    let mut processes = Vec::new();
+    for process in find_all_processes(proc_name, exclude)? {
+        processes.push(process.read_regions()?);
+    }
+    Ok(processes)
+}
+
+/// Attaches to every osu! instance and reads its static addresses
+///
+/// Instances where the signatures can't be read yet (still starting) are
+/// skipped, call it again later to get them
+pub fn attach_all() -> Result<Vec<(State, Process)>, Error> {
+    let mut instances = Vec::new();
+    for p in find_all("osu!.exe", &super::EXCLUDE_WORDS)? {
+        match StaticAddresses::new(&p) {
+            Ok(addresses) => {
+                let state = State {
+                    addresses,
+                    ..Default::default()
+                };
+                instances.push((state, p));
+            }
+            Err(e) => println!("Skipping process {}: {e}", p.pid),
+        }
+    }
+    Ok(instances)
+}
+
+/// Directory of the executable, wine gives windows paths like
+/// `Z:\osu!\osu!.exe`, the drive letter is dropped like rosu-mem does
+#[cfg(target_os = "linux")]
+fn executable_dir(executable: &str) -> Option<PathBuf> {
+    let executable = executable.replace('\\', "/");
+    let executable = match executable.as_bytes() {
+        [_, b':', ..] => &executable[2..],
+        _ => &executable,
+    };
+    PathBuf::from(executable).parent().map(|v| v.to_path_buf())
+}
+
+/// Directory of the executable, the full path with its drive
+#[cfg(target_os = "windows")]
+fn executable_dir(executable: &str) -> Option<PathBuf> {
+    PathBuf::from(executable).parent().map(|v| v.to_path_buf())
+}
+
+/// Arguments the process was started with, the executable first
+#[cfg(target_os = "linux")]
+pub fn command_line(p: &Process) -> Result<Vec<String>, Error> {
+    let cmd_line = std::fs::read(format!("/proc/{}/cmdline", p.pid))?;
+    Ok(String::from_utf8_lossy(&cmd_line)
+        .split('\0')
+        .filter(|arg| !arg.is_empty())
+        .map(String::from)
+        .collect())
+}
+
+/// Arguments the process was started with, the executable first
+#[cfg(target_os = "windows")]
+pub fn command_line(p: &Process) -> Result<Vec<String>, Error> {
+    use windows::Win32::Foundation::UNICODE_STRING;
+    use windows::Win32::System::Threading::{
+        NtQueryInformationProcess, ProcessCommandLineInformation,
+    };
+
+    // the first call fails but gives the size needed
+    let mut size = 0u32;
+    let _ = unsafe {
+        NtQueryInformationProcess(
+            p.handle,
+            ProcessCommandLineInformation,
+            std::ptr::null_mut(),
+            0,
+            &mut size,
+        )
+    };
+    if (size as usize) < size_of::<UNICODE_STRING>() {
+        return Err(Error::NotAvailable("Empty command line".to_string()));
+    }
+    // u64 to align the UNICODE_STRING at the start of the buffer
+    let mut buffer = vec![0u64; (size as usize).div_ceil(size_of::<u64>())];
+    unsafe {
+        NtQueryInformationProcess(
+            p.handle,
+            ProcessCommandLineInformation,
+            buffer.as_mut_ptr().cast(),
+            size,
+            &mut size,
+        )
+    }
+    .map_err(|e| Error::MemoryRead(e.to_string()))?;
+
+    let cmd_line = unsafe {
+        let string = &*(buffer.as_ptr() as *const UNICODE_STRING);
+        std::slice::from_raw_parts(string.Buffer.0, string.Length as usize / 2)
+    };
+    Ok(split_command_line(&String::from_utf16_lossy(cmd_line)))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+pub fn command_line(_p: &Process) -> Result<Vec<String>, Error> {
+    Err(Error::Unsupported(
+        "Unsupported platform for now".to_string(),
+    ))
+}
+
+/// Splits on whitespace, double quotes group an argument with spaces
+#[cfg(target_os = "windows")]
+fn split_command_line(cmd_line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut arg = String::new();
+    let mut quoted = false;
+    for c in cmd_line.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !arg.is_empty() {
+                    args.push(std::mem::take(&mut arg));
+                }
+            }
+            c => arg.push(c),
+        }
+    }
+    if !arg.is_empty() {
+        args.push(arg);
+    }
+    args
+}
+
+#[cfg(target_os = "linux")]
+fn find_all_processes(proc_name: &str, exclude: &[&str]) -> Result<Vec<Process>, Error> {
+    let mut processes = Vec::new();
+    for entry in std::fs::read_dir("/proc")? {
+        let path = entry?.path();
+        let Some(pid) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.parse::<i32>().ok())
+        else {
+            continue;
+        };
+        // processes can exit while iterating
+        let Ok(cmd_line) = std::fs::read_to_string(path.join("cmdline")) else {
+            continue;
+        };
+        let executable = cmd_line.split('\0').next().unwrap_or_default();
+        if !executable.contains(proc_name) || exclude.iter().any(|w| executable.contains(w)) {
+            continue;
+        }
+        processes.push(Process {
+            pid,
+            maps: Vec::new(),
+            executable_dir: executable_dir(executable),
+        });
+    }
+    Ok(processes)
+}
+
+#[cfg(target_os = "windows")]
+fn find_all_processes(proc_name: &str, exclude: &[&str]) -> Result<Vec<Process>, Error> {
+    use windows::Win32::Foundation::{CloseHandle, FALSE, HMODULE};
+    use windows::Win32::System::ProcessStatus::{EnumProcesses, GetModuleFileNameExA};
+    use windows::Win32::System::Threading::{
+        OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ,
+    };
+
+    let mut pids = [0u32; 1024];
+    let mut returned: u32 = 0;
+    unsafe {
+        EnumProcesses(
+            pids.as_mut_ptr(),
+            std::mem::size_of_val(&pids) as u32,
+            &mut returned,
+        )
+    }
+    .ok()
+    .map_err(|e| Error::MemoryRead(e.to_string()))?;
+    let count = returned as usize / std::mem::size_of::<u32>();
+
+    let mut processes = Vec::new();
+    for &pid in &pids[..count] {
+        let Ok(handle) =
+            (unsafe { OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, FALSE, pid) })
+        else {
+            continue;
+        };
+        let mut name = [0u8; 260];
+        let size = unsafe { GetModuleFileNameExA(handle, HMODULE(0), &mut name) };
+        let executable = String::from_utf8_lossy(&name[..size as usize]).to_string();
+        if !executable.contains(proc_name) || exclude.iter().any(|w| executable.contains(w)) {
+            unsafe { CloseHandle(handle) };
+            continue;
+        }
+        processes.push(Process {
+            pid,
+            handle,
+            maps: Vec::new(),
+            executable_dir: executable_dir(&executable),
+        });
+    }
+    Ok(processes)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn find_all_processes(_proc_name: &str, _exclude: &[&str]) -> Result<Vec<Process>, Error> {
+    Err(Error::Unsupported(
+        "Unsupported platform for now".to_string(),
+    ))
+}
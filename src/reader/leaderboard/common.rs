@@ -0,0 +1,114 @@
+use crate::reader::common::GameMode;
+use crate::reader::structs::Hit;
+use crate::scoring::calculate_accuracy;
+use crate::Error;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub name: String,
+    pub score: i32,
+    pub combo: i16,
+    pub max_combo: i16,
+    pub mods: u32,
+    pub hits: Hit,
+    /// 1-based position on the leaderboard
+    pub position: i32,
+    /// multiplayer team, 0 when not playing in teams
+    pub team: i32,
+    /// false once the player failed in multiplayer
+    pub is_passing: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LeaderboardInfo {
+    pub is_visible: bool,
+    /// live slot of the player, `None` when watching or not shown
+    pub player: Option<LeaderboardEntry>,
+    /// every slot sorted by position, including the player's
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl LeaderboardInfo {
+    pub fn player_position(&self) -> Option<i32> {
+        self.player.as_ref().map(|p| p.position)
+    }
+
+    /// Entry right above the player
+    pub fn next_target(&self) -> Option<&LeaderboardEntry> {
+        let position = self.player_position()?;
+        self.entries.iter().find(|e| e.position == position - 1)
+    }
+
+    /// Score needed to pass the entry right above the player
+    pub fn score_behind_next(&self) -> Option<i32> {
+        let player = self.player.as_ref()?;
+        Some(self.next_target()?.score - player.score)
+    }
+}
+
+/// Leaderboard selected on song select
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LeaderboardType {
+    Local,
+    Global,
+    SelectedMods,
+    Friends,
+    Country,
+    #[default]
+    Unknown,
+}
+
+impl From<i32> for LeaderboardType {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => Self::Local,
+            1 => Self::Global,
+            2 => Self::SelectedMods,
+            3 => Self::Friends,
+            4 => Self::Country,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SongSelectScore {
+    pub name: String,
+    pub score: i32,
+    pub max_combo: i16,
+    pub perfect: bool,
+    pub mods: u32,
+    pub hits: Hit,
+}
+
+impl SongSelectScore {
+    pub fn accuracy(&self, mode: &GameMode) -> Result<f64, Error> {
+        calculate_accuracy(mode, &self.hits)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SongSelectLeaderboard {
+    pub kind: LeaderboardType,
+    /// md5 of the selected beatmap the scores belong to
+    pub beatmap_md5: String,
+    /// sorted by score, best first
+    pub scores: Vec<SongSelectScore>,
+}
+
+impl SongSelectLeaderboard {
+    /// Best score of the given player on the leaderboard
+    pub fn personal_best(&self, player: &str) -> Option<&SongSelectScore> {
+        self.scores.iter().find(|s| s.name == player)
+    }
+
+    /// 1-based position a score would get on the leaderboard
+    pub fn position_for(&self, score: i32) -> usize {
+        self.scores.iter().filter(|s| s.score >= score).count() + 1
+    }
+
+    /// Score to beat to reach the given 1-based position
+    pub fn target(&self, position: usize) -> Option<&SongSelectScore> {
+        self.scores.get(position.checked_sub(1)?)
+    }
+}
@@ -0,0 +1,39 @@
+pub mod common;
+pub mod stable;
+
+use crate::impl_osu_accessor;
+use crate::reader::common::OsuClientKind;
+use crate::reader::leaderboard::common::{
+    LeaderboardEntry, LeaderboardInfo, LeaderboardType, SongSelectLeaderboard, SongSelectScore,
+};
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Leaderboard shown on the side during gameplay (solo or multiplayer) and
+/// on song select
+pub struct LeaderboardReader<'a> {
+    pub process: &'a Process,
+    pub state: &'a mut State,
+    pub osu_type: OsuClientKind,
+}
+
+impl<'a> LeaderboardReader<'a> {
+    pub fn new(p: &'a Process, state: &'a mut State, osu_type: OsuClientKind) -> Self {
+        Self {
+            process: p,
+            state,
+            osu_type,
+        }
+    }
+    impl_osu_accessor! {
+        fn is_visible() -> bool => stable::memory::is_visible,
+        fn player() -> Option<LeaderboardEntry> => stable::memory::player,
+        fn player_position() -> Option<i32> => stable::memory::player_position,
+        fn entries() -> Vec<LeaderboardEntry> => stable::memory::entries,
+        fn info() -> LeaderboardInfo => stable::memory::info,
+        fn song_select_leaderboard_type() -> LeaderboardType => stable::memory::song_select_leaderboard_type,
+        fn song_select_scores() -> Vec<SongSelectScore> => stable::memory::song_select_scores,
+        fn song_select_leaderboard() -> SongSelectLeaderboard => stable::memory::song_select_leaderboard,
+    }
+}
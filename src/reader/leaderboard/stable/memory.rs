@@ -0,0 +1,190 @@
+use crate::reader::common::stable::memory::check_game_state;
+use crate::reader::common::GameState;
+use crate::reader::gameplay::stable::memory::ruleset_addr;
+use crate::reader::gameplay::stable::offset::GAMEPLAY_OFFSET;
+use crate::reader::helpers::read_list_i32;
+use crate::reader::leaderboard::common::{
+    LeaderboardEntry, LeaderboardInfo, LeaderboardType, SongSelectLeaderboard, SongSelectScore,
+};
+use crate::reader::leaderboard::stable::offset::{
+    LEADERBOARD_OFFSET, SONG_SELECT_LEADERBOARD_OFFSET,
+};
+use crate::reader::structs::{Hit, State};
+use crate::Error;
+use rosu_mem::process::{Process, ProcessTraits};
+use std::mem::size_of;
+
+pub fn leaderboard_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let ruleset_addr = ruleset_addr(p, state)?;
+    let base = p.read_i32(ruleset_addr + LEADERBOARD_OFFSET.base)?;
+    if base == 0 {
+        return Err(Error::NotAvailable("No leaderboard loaded".to_string()));
+    }
+    let addr = p.read_i32(base + LEADERBOARD_OFFSET.addr)?;
+    if addr == 0 {
+        return Err(Error::NotAvailable("No leaderboard loaded".to_string()));
+    }
+    Ok(addr)
+}
+
+pub fn is_visible(p: &Process, state: &mut State) -> Result<bool, Error> {
+    let leaderboard_addr = leaderboard_addr(p, state)?;
+    let visible_base = p.read_i32(leaderboard_addr + LEADERBOARD_OFFSET.visible_base)?;
+    Ok(p.read_u8(visible_base + LEADERBOARD_OFFSET.visible)? != 0)
+}
+
+pub fn player(p: &Process, state: &mut State) -> Result<Option<LeaderboardEntry>, Error> {
+    let leaderboard_addr = leaderboard_addr(p, state)?;
+    let player_addr = p.read_i32(leaderboard_addr + LEADERBOARD_OFFSET.player)?;
+    if player_addr == 0 {
+        return Ok(None);
+    }
+    Ok(Some(entry(p, player_addr)?))
+}
+
+pub fn player_position(p: &Process, state: &mut State) -> Result<Option<i32>, Error> {
+    Ok(player(p, state)?.map(|player| player.position))
+}
+
+pub fn entries(p: &Process, state: &mut State) -> Result<Vec<LeaderboardEntry>, Error> {
+    let leaderboard_addr = leaderboard_addr(p, state)?;
+    read_entries(p, leaderboard_addr)
+}
+
+pub fn info(p: &Process, state: &mut State) -> Result<LeaderboardInfo, Error> {
+    let leaderboard_addr = leaderboard_addr(p, state)?;
+    let visible_base = p.read_i32(leaderboard_addr + LEADERBOARD_OFFSET.visible_base)?;
+    let player_addr = p.read_i32(leaderboard_addr + LEADERBOARD_OFFSET.player)?;
+    let player = if player_addr == 0 {
+        None
+    } else {
+        Some(entry(p, player_addr)?)
+    };
+
+    Ok(LeaderboardInfo {
+        is_visible: p.read_u8(visible_base + LEADERBOARD_OFFSET.visible)? != 0,
+        player,
+        entries: read_entries(p, leaderboard_addr)?,
+    })
+}
+
+fn read_entries(p: &Process, leaderboard_addr: i32) -> Result<Vec<LeaderboardEntry>, Error> {
+    let list_addr = p.read_i32(leaderboard_addr + LEADERBOARD_OFFSET.entries)?;
+    let mut entries = read_list_i32(p, list_addr, 0)?
+        .into_iter()
+        .filter(|&addr| addr != 0)
+        .map(|addr| entry(p, addr))
+        .collect::<Result<Vec<_>, Error>>()?;
+    entries.sort_by_key(|e| e.position);
+    Ok(entries)
+}
+
+fn entry(p: &Process, entry_addr: i32) -> Result<LeaderboardEntry, Error> {
+    let offset = &LEADERBOARD_OFFSET.entry;
+    let score_base = p.read_i32(entry_addr + offset.score_base)?;
+    let mods_xor_base = p.read_i32(score_base + offset.mods)?;
+    let mods = p.read_i32(mods_xor_base + offset.mods_xor)?
+        ^ p.read_i32(mods_xor_base + offset.mods_xor2)?;
+
+    Ok(LeaderboardEntry {
+        name: p.read_string(entry_addr + offset.name)?,
+        score: p.read_i32(entry_addr + offset.score)?,
+        combo: p.read_i16(score_base + offset.combo)?,
+        max_combo: p.read_i16(score_base + offset.max_combo)?,
+        mods: mods as u32,
+        hits: read_hits(p, score_base + offset.hits)?,
+        position: p.read_i32(entry_addr + offset.position)?,
+        team: p.read_i32(entry_addr + offset.team)?,
+        is_passing: p.read_u8(entry_addr + offset.passing)? != 0,
+    })
+}
+
+pub fn song_select_leaderboard_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    if !check_game_state(p, state, GameState::SongSelect)? {
+        return Err(Error::NotAvailable("Not in SongSelect".to_string()));
+    }
+    let rulesets_addr = p.read_i32(state.addresses.rulesets - GAMEPLAY_OFFSET.ptr)?;
+    let screen_addr = p.read_i32(rulesets_addr + SONG_SELECT_LEADERBOARD_OFFSET.screen)?;
+    let addr = p.read_i32(screen_addr + SONG_SELECT_LEADERBOARD_OFFSET.leaderboard)?;
+    if addr == 0 {
+        return Err(Error::NotAvailable("No leaderboard loaded".to_string()));
+    }
+    Ok(addr)
+}
+
+pub fn song_select_leaderboard_type(
+    p: &Process,
+    state: &mut State,
+) -> Result<LeaderboardType, Error> {
+    let leaderboard_addr = song_select_leaderboard_addr(p, state)?;
+    Ok(LeaderboardType::from(p.read_i32(
+        leaderboard_addr + SONG_SELECT_LEADERBOARD_OFFSET.kind,
+    )?))
+}
+
+pub fn song_select_scores(p: &Process, state: &mut State) -> Result<Vec<SongSelectScore>, Error> {
+    let leaderboard_addr = song_select_leaderboard_addr(p, state)?;
+    read_song_select_scores(p, leaderboard_addr)
+}
+
+pub fn song_select_leaderboard(
+    p: &Process,
+    state: &mut State,
+) -> Result<SongSelectLeaderboard, Error> {
+    let leaderboard_addr = song_select_leaderboard_addr(p, state)?;
+    Ok(SongSelectLeaderboard {
+        kind: LeaderboardType::from(
+            p.read_i32(leaderboard_addr + SONG_SELECT_LEADERBOARD_OFFSET.kind)?,
+        ),
+        beatmap_md5: crate::reader::beatmap::stable::memory::md5(p, state)?,
+        scores: read_song_select_scores(p, leaderboard_addr)?,
+    })
+}
+
+fn read_song_select_scores(
+    p: &Process,
+    leaderboard_addr: i32,
+) -> Result<Vec<SongSelectScore>, Error> {
+    let list_addr = p.read_i32(leaderboard_addr + SONG_SELECT_LEADERBOARD_OFFSET.scores)?;
+    if list_addr == 0 {
+        return Ok(Vec::new());
+    }
+    let mut scores = read_list_i32(p, list_addr, 0)?
+        .into_iter()
+        .filter(|&addr| addr != 0)
+        .map(|addr| song_select_score(p, addr))
+        .collect::<Result<Vec<_>, Error>>()?;
+    scores.sort_by_key(|s| std::cmp::Reverse(s.score));
+    Ok(scores)
+}
+
+fn song_select_score(p: &Process, score_addr: i32) -> Result<SongSelectScore, Error> {
+    let offset = &SONG_SELECT_LEADERBOARD_OFFSET.score;
+    let mods_xor_base = p.read_i32(score_addr + offset.mods)?;
+    let mods = p.read_i32(mods_xor_base + offset.mods_xor)?
+        ^ p.read_i32(mods_xor_base + offset.mods_xor2)?;
+
+    Ok(SongSelectScore {
+        name: p.read_string(score_addr + offset.name)?,
+        score: p.read_i32(score_addr + offset.score)?,
+        max_combo: p.read_i16(score_addr + offset.max_combo)?,
+        perfect: p.read_u8(score_addr + offset.perfect)? != 0,
+        mods: mods as u32,
+        hits: read_hits(p, score_addr + offset.hits)?,
+    })
+}
+
+/// Reads the hits of a score in one memory operation
+fn read_hits(p: &Process, hits_addr: i32) -> Result<Hit, Error> {
+    let mut hits_buffer = [0u8; size_of::<i16>() * 6];
+    p.read(hits_addr, size_of::<i16>() * 6, &mut hits_buffer)?;
+
+    Ok(Hit {
+        _100: i16::from_le_bytes(hits_buffer[0..2].try_into().unwrap()),
+        _300: i16::from_le_bytes(hits_buffer[2..4].try_into().unwrap()),
+        _50: i16::from_le_bytes(hits_buffer[4..6].try_into().unwrap()),
+        _geki: i16::from_le_bytes(hits_buffer[6..8].try_into().unwrap()),
+        _katu: i16::from_le_bytes(hits_buffer[8..10].try_into().unwrap()),
+        _miss: i16::from_le_bytes(hits_buffer[10..12].try_into().unwrap()),
+    })
+}
@@ -0,0 +1,92 @@
+pub struct LeaderboardOffset {
+    pub base: i32,
+    pub addr: i32,
+    pub entries: i32,
+    pub player: i32,
+    pub visible_base: i32,
+    pub visible: i32,
+    pub entry: LeaderboardEntryOffset,
+}
+
+pub(crate) const LEADERBOARD_OFFSET: LeaderboardOffset = LeaderboardOffset {
+    base: 0x7C, // from the ruleset, null when there is no leaderboard
+    addr: 0x24,
+    entries: 0x4, // List<ScoreboardEntry>
+    player: 0x10,
+    visible_base: 0x24,
+    visible: 0x20,
+    entry: LEADERBOARD_ENTRY_OFFSET,
+};
+
+pub struct LeaderboardEntryOffset {
+    pub score_base: i32,
+    pub name: i32,
+    pub score: i32,
+    pub position: i32,
+    pub team: i32,
+    pub passing: i32,
+    /// from the score of the entry, same layout as the gameplay score
+    pub combo: i32,
+    pub max_combo: i32,
+    pub mods: i32,
+    pub mods_xor: i32,
+    pub mods_xor2: i32,
+    pub hits: i32,
+}
+
+pub(crate) const LEADERBOARD_ENTRY_OFFSET: LeaderboardEntryOffset = LeaderboardEntryOffset {
+    score_base: 0x20,
+    name: 0x8,
+    score: 0x30,
+    position: 0x2C,
+    team: 0x40,
+    passing: 0x4B,
+    combo: 0x94,
+    max_combo: 0x68,
+    mods: 0x1C,
+    mods_xor: 0xC,
+    mods_xor2: 0x8,
+    hits: 0x88, // 100, 300, 50, geki, katu, miss as i16
+};
+
+pub struct SongSelectLeaderboardOffset {
+    pub screen: i32,
+    pub leaderboard: i32,
+    pub kind: i32,
+    pub scores: i32,
+    /// from each score, same layout as the gameplay score
+    pub score: SongSelectScoreOffset,
+}
+
+// The song select screen is the current mode behind the rulesets address like
+// the gameplay ruleset, leaderboard offsets are not checked on every osu! build
+pub(crate) const SONG_SELECT_LEADERBOARD_OFFSET: SongSelectLeaderboardOffset =
+    SongSelectLeaderboardOffset {
+        screen: 0x4,
+        leaderboard: 0xD4,
+        kind: 0x2C,
+        scores: 0x8, // List<Score>
+        score: SONG_SELECT_SCORE_OFFSET,
+    };
+
+pub struct SongSelectScoreOffset {
+    pub name: i32,
+    pub score: i32,
+    pub max_combo: i32,
+    pub perfect: i32,
+    pub mods: i32,
+    pub mods_xor: i32,
+    pub mods_xor2: i32,
+    pub hits: i32,
+}
+
+pub(crate) const SONG_SELECT_SCORE_OFFSET: SongSelectScoreOffset = SongSelectScoreOffset {
+    name: 0x28,
+    score: 0x78,
+    max_combo: 0x68,
+    perfect: 0xA6,
+    mods: 0x1C,
+    mods_xor: 0xC,
+    mods_xor2: 0x8,
+    hits: 0x88, // 100, 300, 50, geki, katu, miss as i16
+};
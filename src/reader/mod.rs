@@ -1,9 +1,17 @@
 pub mod beatmap;
+pub mod chat;
 pub mod common;
 pub mod gameplay;
 pub mod helpers;
+pub mod instances;
+pub mod leaderboard;
+pub mod multiplayer;
 pub mod resultscreen;
+pub mod settings;
+pub mod skin;
+pub mod tourney;
 pub mod user;
+pub mod wine;
 
 use crate::reader::common::stable::memory::game_state;
 use crate::reader::common::GameState;
@@ -39,9 +47,7 @@ where
 
 #[allow(dead_code)]
 pub fn init_loop(sleep_duration: u64) -> Result<(State, Process), Error> {
-    let mut state = State {
-        addresses: StaticAddresses::default(),
-    };
+    let mut state = State::default();
 
     loop {
         match Process::initialize("osu!.exe", &EXCLUDE_WORDS) {
@@ -89,21 +95,3 @@ pub fn init_loop(sleep_duration: u64) -> Result<(State, Process), Error> {
         }
     }
 }
-
-// Exemple of playing loop
-// pub(crate) fn playing(p: &Process, state: &mut State) -> bool {
-//     let mode_list = get_mods(p, state);
-//     while (GameState::from(get_status(p, state)) == GameState::Playing) {
-//         cur_time = reader_gameplay::get_ig_time(p, state);
-//         if (cur_time - last_time < 20 && cur_time > 0 && last_time > 0 && last_paused != cur_time) {
-//             last_paused = cur_time;
-//         }
-//         last_time = cur_time;
-//         let status = get_status(p, state);
-//         let md5 = get_beatmap_md5(p, state);
-//         if (last_retries < get_retries(p, state)) {
-//             return false;
-//         }
-//     }
-//     true
-// }
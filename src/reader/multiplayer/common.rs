@@ -0,0 +1,178 @@
+use crate::reader::common::GameMode;
+
+/// Mod applied to the room letting each player pick their mods
+#[cfg(feature = "unverified-multiplayer")]
+const FREE_MOD: i32 = 1;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SlotStatus {
+    Open,
+    Locked,
+    NotReady,
+    Ready,
+    NoMap,
+    Playing,
+    Complete,
+    Quit,
+    #[default]
+    Unknown,
+}
+
+impl From<i32> for SlotStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => Self::Open,
+            2 => Self::Locked,
+            4 => Self::NotReady,
+            8 => Self::Ready,
+            16 => Self::NoMap,
+            32 => Self::Playing,
+            64 => Self::Complete,
+            128 => Self::Quit,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl SlotStatus {
+    pub fn has_player(&self) -> bool {
+        matches!(
+            self,
+            Self::NotReady | Self::Ready | Self::NoMap | Self::Playing | Self::Complete
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Team {
+    #[default]
+    None,
+    Blue,
+    Red,
+}
+
+impl From<i32> for Team {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => Self::Blue,
+            2 => Self::Red,
+            _ => Self::None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TeamMode {
+    HeadToHead,
+    TagCoop,
+    TeamVs,
+    TagTeamVs,
+    #[default]
+    Unknown,
+}
+
+impl From<i32> for TeamMode {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => Self::HeadToHead,
+            1 => Self::TagCoop,
+            2 => Self::TeamVs,
+            3 => Self::TagTeamVs,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl TeamMode {
+    pub fn has_teams(&self) -> bool {
+        matches!(self, Self::TeamVs | Self::TagTeamVs)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WinCondition {
+    Score,
+    Accuracy,
+    Combo,
+    ScoreV2,
+    #[default]
+    Unknown,
+}
+
+impl From<i32> for WinCondition {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => Self::Score,
+            1 => Self::Accuracy,
+            2 => Self::Combo,
+            3 => Self::ScoreV2,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiplayerSlot {
+    /// 0-based slot index
+    pub index: usize,
+    pub status: SlotStatus,
+    pub team: Team,
+    /// 0 for empty slots
+    pub user_id: i32,
+    pub player: Option<String>,
+    /// mods picked by the player when free mod is on
+    pub mods: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiplayerBeatmap {
+    pub id: i32,
+    pub md5: String,
+    /// "Artist - Title \[Difficulty\]" as shown in the room
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiplayerRoom {
+    pub id: i32,
+    pub name: String,
+    pub host_id: i32,
+    pub mode: GameMode,
+    pub team_mode: TeamMode,
+    pub win_condition: WinCondition,
+    /// room mods, the slot mods are added to them with free mod
+    pub mods: u32,
+    pub free_mod: bool,
+    pub beatmap: MultiplayerBeatmap,
+    /// every slot of the room, including the empty and locked ones
+    pub slots: Vec<MultiplayerSlot>,
+}
+
+impl MultiplayerRoom {
+    #[cfg(feature = "unverified-multiplayer")]
+    pub(crate) fn is_free_mod(special_modes: i32) -> bool {
+        special_modes & FREE_MOD != 0
+    }
+
+    /// Slots with a player in them
+    pub fn players(&self) -> impl Iterator<Item = &MultiplayerSlot> {
+        self.slots.iter().filter(|s| s.status.has_player())
+    }
+
+    pub fn host(&self) -> Option<&MultiplayerSlot> {
+        self.players().find(|s| s.user_id == self.host_id)
+    }
+
+    pub fn host_name(&self) -> Option<&str> {
+        self.host()?.player.as_deref()
+    }
+
+    pub fn team(&self, team: Team) -> impl Iterator<Item = &MultiplayerSlot> {
+        self.players().filter(move |s| s.team == team)
+    }
+
+    pub fn is_everyone_ready(&self) -> bool {
+        let mut players = self.players().peekable();
+        players.peek().is_some() && players.all(|s| s.status == SlotStatus::Ready)
+    }
+}
@@ -0,0 +1,56 @@
+pub mod common;
+#[cfg(feature = "unverified-multiplayer")]
+pub mod stable;
+
+#[cfg(feature = "unverified-multiplayer")]
+use crate::impl_osu_accessor;
+#[cfg(feature = "unverified-multiplayer")]
+use crate::reader::common::{GameMode, OsuClientKind};
+#[cfg(feature = "unverified-multiplayer")]
+use crate::reader::multiplayer::common::{
+    MultiplayerBeatmap, MultiplayerRoom, MultiplayerSlot, TeamMode, WinCondition,
+};
+#[cfg(feature = "unverified-multiplayer")]
+use crate::reader::structs::State;
+#[cfg(feature = "unverified-multiplayer")]
+use crate::Error;
+#[cfg(feature = "unverified-multiplayer")]
+use rosu_mem::process::Process;
+
+/// Multiplayer room joined, available from the lobby to the result screen
+///
+/// The room offsets are not verified on a real client yet, it needs the
+/// `unverified-multiplayer` feature. The room is checked when read and gives
+/// `Error::NotAvailable` when its values can't be right
+#[cfg(feature = "unverified-multiplayer")]
+pub struct MultiplayerReader<'a> {
+    pub process: &'a Process,
+    pub state: &'a mut State,
+    pub osu_type: OsuClientKind,
+}
+
+#[cfg(feature = "unverified-multiplayer")]
+impl<'a> MultiplayerReader<'a> {
+    pub fn new(p: &'a Process, state: &'a mut State, osu_type: OsuClientKind) -> Self {
+        Self {
+            process: p,
+            state,
+            osu_type,
+        }
+    }
+    impl_osu_accessor! {
+        fn is_in_room() -> bool => stable::memory::is_in_room,
+        fn room_id() -> i32 => stable::memory::room_id,
+        fn room_name() -> String => stable::memory::room_name,
+        fn host_id() -> i32 => stable::memory::host_id,
+        fn host_name() -> Option<String> => stable::memory::host_name,
+        fn mode() -> GameMode => stable::memory::mode,
+        fn team_mode() -> TeamMode => stable::memory::team_mode,
+        fn win_condition() -> WinCondition => stable::memory::win_condition,
+        fn mods() -> u32 => stable::memory::mods,
+        fn is_free_mod() -> bool => stable::memory::is_free_mod,
+        fn beatmap() -> MultiplayerBeatmap => stable::memory::beatmap,
+        fn slots() -> Vec<MultiplayerSlot> => stable::memory::slots,
+        fn room() -> MultiplayerRoom => stable::memory::room,
+    }
+}
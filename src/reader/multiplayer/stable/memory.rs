@@ -0,0 +1,160 @@
+use crate::generate_offset_getter;
+use crate::reader::common::stable::memory::game_state;
+use crate::reader::common::{GameMode, GameState};
+use crate::reader::gameplay::stable::offset::GAMEPLAY_OFFSET;
+use crate::reader::helpers::{read_array_i32, read_i32, read_string, read_u32};
+use crate::reader::multiplayer::common::{
+    MultiplayerBeatmap, MultiplayerRoom, MultiplayerSlot, SlotStatus, Team, TeamMode, WinCondition,
+};
+use crate::reader::multiplayer::stable::offset::{MULTIPLAYER_OFFSET, SLOT_COUNT};
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::{Process, ProcessTraits};
+
+pub fn room_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    if !matches!(
+        game_state(p, state)?,
+        GameState::MultiplayerLobby
+            | GameState::MultiplayerSongSelect
+            | GameState::MultiplayerResultScreen
+            | GameState::MultiplayerResultScreenTagCoop
+            | GameState::MultiplayerResultScreenTeamVs
+    ) {
+        return Err(Error::NotAvailable("Not in a multiplayer room".to_string()));
+    }
+    let rulesets_addr = p.read_i32(state.addresses.rulesets - GAMEPLAY_OFFSET.ptr)?;
+    let screen_addr = p.read_i32(rulesets_addr + MULTIPLAYER_OFFSET.screen)?;
+    let addr = p.read_i32(screen_addr + MULTIPLAYER_OFFSET.room)?;
+    if addr == 0 {
+        return Err(Error::NotAvailable("Not in a multiplayer room".to_string()));
+    }
+    check_room(p, addr)?;
+    Ok(addr)
+}
+
+/// Most room offsets are unconfirmed, values only a room can have are
+/// checked so a wrong layout gives an error instead of garbage
+fn check_room(p: &Process, room_addr: i32) -> Result<(), Error> {
+    let statuses = read_array_i32(p, p.read_i32(room_addr + MULTIPLAYER_OFFSET.slot.status)?)?;
+    let team_mode = TeamMode::from(p.read_i32(room_addr + MULTIPLAYER_OFFSET.team_mode)?);
+    let win_condition =
+        WinCondition::from(p.read_i32(room_addr + MULTIPLAYER_OFFSET.win_condition)?);
+    if statuses.len() != SLOT_COUNT as usize
+        || statuses
+            .iter()
+            .any(|&s| SlotStatus::from(s) == SlotStatus::Unknown)
+        || team_mode == TeamMode::Unknown
+        || win_condition == WinCondition::Unknown
+    {
+        return Err(Error::NotAvailable(
+            "Multiplayer room looks invalid, the room offsets may be wrong for this osu! build"
+                .to_string(),
+        ));
+    }
+    Ok(())
+}
+
+pub fn is_in_room(p: &Process, state: &mut State) -> Result<bool, Error> {
+    match room_addr(p, state) {
+        Ok(_) => Ok(true),
+        Err(Error::NotAvailable(_)) => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+generate_offset_getter! {
+    room_id: i32 = read_i32(MULTIPLAYER_OFFSET.id, room_addr);
+    room_name: String = read_string(MULTIPLAYER_OFFSET.name, room_addr);
+    host_id: i32 = read_i32(MULTIPLAYER_OFFSET.host_id, room_addr);
+    mode: GameMode = read_i32(MULTIPLAYER_OFFSET.mode, room_addr);
+    team_mode: TeamMode = read_i32(MULTIPLAYER_OFFSET.team_mode, room_addr);
+    win_condition: WinCondition = read_i32(MULTIPLAYER_OFFSET.win_condition, room_addr);
+    mods: u32 = read_u32(MULTIPLAYER_OFFSET.mods, room_addr);
+    special_modes: i32 = read_i32(MULTIPLAYER_OFFSET.special_modes, room_addr);
+    beatmap_id: i32 = read_i32(MULTIPLAYER_OFFSET.beatmap_id, room_addr);
+    beatmap_md5: String = read_string(MULTIPLAYER_OFFSET.beatmap_md5, room_addr);
+    beatmap_name: String = read_string(MULTIPLAYER_OFFSET.beatmap_name, room_addr);
+}
+
+pub fn is_free_mod(p: &Process, state: &mut State) -> Result<bool, Error> {
+    Ok(MultiplayerRoom::is_free_mod(special_modes(p, state)?))
+}
+
+pub fn beatmap(p: &Process, state: &mut State) -> Result<MultiplayerBeatmap, Error> {
+    let room_addr = room_addr(p, state)?;
+    read_beatmap(p, room_addr)
+}
+
+pub fn slots(p: &Process, state: &mut State) -> Result<Vec<MultiplayerSlot>, Error> {
+    let room_addr = room_addr(p, state)?;
+    read_slots(p, room_addr)
+}
+
+pub fn host_name(p: &Process, state: &mut State) -> Result<Option<String>, Error> {
+    let host_id = host_id(p, state)?;
+    Ok(slots(p, state)?
+        .into_iter()
+        .find(|s| s.status.has_player() && s.user_id == host_id)
+        .and_then(|s| s.player))
+}
+
+pub fn room(p: &Process, state: &mut State) -> Result<MultiplayerRoom, Error> {
+    let room_addr = room_addr(p, state)?;
+    Ok(MultiplayerRoom {
+        id: p.read_i32(room_addr + MULTIPLAYER_OFFSET.id)?,
+        name: p.read_string(room_addr + MULTIPLAYER_OFFSET.name)?,
+        host_id: p.read_i32(room_addr + MULTIPLAYER_OFFSET.host_id)?,
+        mode: GameMode::from(p.read_i32(room_addr + MULTIPLAYER_OFFSET.mode)?),
+        team_mode: TeamMode::from(p.read_i32(room_addr + MULTIPLAYER_OFFSET.team_mode)?),
+        win_condition: WinCondition::from(
+            p.read_i32(room_addr + MULTIPLAYER_OFFSET.win_condition)?,
+        ),
+        mods: p.read_u32(room_addr + MULTIPLAYER_OFFSET.mods)?,
+        free_mod: MultiplayerRoom::is_free_mod(
+            p.read_i32(room_addr + MULTIPLAYER_OFFSET.special_modes)?,
+        ),
+        beatmap: read_beatmap(p, room_addr)?,
+        slots: read_slots(p, room_addr)?,
+    })
+}
+
+fn read_beatmap(p: &Process, room_addr: i32) -> Result<MultiplayerBeatmap, Error> {
+    Ok(MultiplayerBeatmap {
+        id: p.read_i32(room_addr + MULTIPLAYER_OFFSET.beatmap_id)?,
+        md5: p.read_string(room_addr + MULTIPLAYER_OFFSET.beatmap_md5)?,
+        name: p.read_string(room_addr + MULTIPLAYER_OFFSET.beatmap_name)?,
+    })
+}
+
+fn read_slots(p: &Process, room_addr: i32) -> Result<Vec<MultiplayerSlot>, Error> {
+    let offset = &MULTIPLAYER_OFFSET.slot;
+    let array = |offset: i32| read_array_i32(p, p.read_i32(room_addr + offset)?);
+    let statuses = array(offset.status)?;
+    let teams = array(offset.team)?;
+    let user_ids = array(offset.user_id)?;
+    let mods = array(offset.mods)?;
+    let users = array(offset.user)?;
+
+    let at = |items: &[i32], index: usize| items.get(index).copied().unwrap_or_default();
+    statuses
+        .iter()
+        .enumerate()
+        .map(|(index, &status)| {
+            let status = SlotStatus::from(status);
+            let user_addr = at(&users, index);
+            let player = if status.has_player() && user_addr != 0 {
+                Some(p.read_string(user_addr + offset.username)?)
+            } else {
+                None
+            };
+            Ok(MultiplayerSlot {
+                index,
+                status,
+                team: Team::from(at(&teams, index)),
+                user_id: at(&user_ids, index),
+                player,
+                mods: at(&mods, index) as u32,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,61 @@
+pub struct MultiplayerOffset {
+    pub screen: i32,
+    pub room: i32,
+    pub id: i32,
+    pub name: i32,
+    pub beatmap_name: i32,
+    pub beatmap_md5: i32,
+    pub beatmap_id: i32,
+    pub mods: i32,
+    pub host_id: i32,
+    pub mode: i32,
+    pub win_condition: i32,
+    pub team_mode: i32,
+    pub special_modes: i32,
+    pub slot: MultiplayerSlotOffset,
+}
+
+/// Number of slots of every room, the length of each slot array
+pub(crate) const SLOT_COUNT: i32 = 16;
+
+// The lobby screen is the current mode behind the rulesets address like the
+// gameplay ruleset. Only `screen` (the gameplay ruleset read) and the slot
+// `username` (the user layout of the user reader) are confirmed, every other
+// offset is unconfirmed on a real client, the room is checked when read and
+// everything reading it is behind the `unverified-multiplayer` feature
+pub(crate) const MULTIPLAYER_OFFSET: MultiplayerOffset = MultiplayerOffset {
+    screen: 0x4,
+    room: 0x48,          // unconfirmed, null until the room is joined
+    id: 0x30,            // unconfirmed
+    name: 0x8,           // unconfirmed
+    beatmap_name: 0x10,  // unconfirmed
+    beatmap_md5: 0x14,   // unconfirmed
+    beatmap_id: 0x34,    // unconfirmed
+    mods: 0x38,          // unconfirmed
+    host_id: 0x3C,       // unconfirmed
+    mode: 0x40,          // unconfirmed
+    win_condition: 0x44, // unconfirmed, checked when read
+    team_mode: 0x48,     // unconfirmed, checked when read
+    special_modes: 0x4C, // unconfirmed
+    slot: MULTIPLAYER_SLOT_OFFSET,
+};
+
+/// Each of these is an array of `SLOT_COUNT` items, one per slot
+pub struct MultiplayerSlotOffset {
+    pub status: i32,
+    pub team: i32,
+    pub user_id: i32,
+    pub mods: i32,
+    pub user: i32,
+    /// from each user of `user`, same layout as the spectated user
+    pub username: i32,
+}
+
+pub(crate) const MULTIPLAYER_SLOT_OFFSET: MultiplayerSlotOffset = MultiplayerSlotOffset {
+    status: 0x18,   // int[], unconfirmed, checked when read
+    team: 0x1C,     // int[], unconfirmed
+    user_id: 0x20,  // int[], unconfirmed
+    mods: 0x24,     // int[], unconfirmed
+    user: 0x28,     // User[], unconfirmed, null items for empty slots
+    username: 0x30, // same as the user reader
+};
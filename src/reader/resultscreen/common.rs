@@ -1,5 +1,6 @@
-use crate::reader::common::GameMode;
+use crate::reader::common::{GameMode, Grade, PlayKind};
 use crate::reader::structs::Hit;
+use crate::Error;
 
 #[derive(Debug, Default)]
 pub struct ResultScreenInfo {
@@ -9,4 +10,80 @@ pub struct ResultScreenInfo {
     pub score: i32,
     pub hits: Hit,
     pub accuracy: f64,
+    pub mods: u32,
+    pub grade: Grade,
+    /// unix time in ms the score was set
+    pub date: i64,
+    /// 0 until the score is submitted
+    pub online_id: i64,
+    pub beatmap_md5: String,
+    pub kind: PlayKind,
+}
+
+impl ResultScreenInfo {
+    /// Whether the result screen is the end of a watched replay, autoplay
+    /// and spectated plays are replays for osu!
+    pub fn is_replay(&self) -> bool {
+        self.kind != PlayKind::Play
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ResultScreenPerformance {
+    pub stars: f64,
+    pub pp: f64,
+    /// pp with the misses turned into 300s and full combo
+    pub fc_pp: f64,
+}
+
+impl ResultScreenPerformance {
+    /// Computes the pp of a score on an already parsed beatmap, `mode` can be
+    /// a convert of the beatmap mode
+    pub fn new(
+        b: &rosu_pp::Beatmap,
+        mode: GameMode,
+        hits: &Hit,
+        max_combo: i16,
+        mods: u32,
+    ) -> Result<Self, Error> {
+        let mode = match mode {
+            GameMode::Osu => rosu_pp::model::mode::GameMode::Osu,
+            GameMode::Taiko => rosu_pp::model::mode::GameMode::Taiko,
+            GameMode::Catch => rosu_pp::model::mode::GameMode::Catch,
+            GameMode::Mania => rosu_pp::model::mode::GameMode::Mania,
+            GameMode::Unknown => {
+                return Err(Error::Unsupported("Unknown game mode".to_string()));
+            }
+        };
+        // osu! stores the counts as unsigned 16 bits
+        let count = |value: i16| value as u16 as u32;
+
+        let score = rosu_pp::Performance::new(b)
+            .mode_or_ignore(mode)
+            .mods(mods)
+            .combo(count(max_combo))
+            .n_geki(count(hits._geki))
+            .n300(count(hits._300))
+            .n_katu(count(hits._katu))
+            .n100(count(hits._100))
+            .n50(count(hits._50))
+            .misses(count(hits._miss))
+            .calculate();
+        let stars = score.stars();
+        let pp = score.pp();
+
+        // difficulty is reused, only the score changes
+        let fc_pp = rosu_pp::Performance::new(score.difficulty_attributes())
+            .mods(mods)
+            .n_geki(count(hits._geki))
+            .n300(count(hits._300) + count(hits._miss))
+            .n_katu(count(hits._katu))
+            .n100(count(hits._100))
+            .n50(count(hits._50))
+            .misses(0)
+            .calculate()
+            .pp();
+
+        Ok(Self { stars, pp, fc_pp })
+    }
 }
@@ -2,8 +2,10 @@ pub mod common;
 pub mod stable;
 use crate::impl_osu_accessor;
 use crate::reader::common::GameMode;
+use crate::reader::common::Grade;
 use crate::reader::common::OsuClientKind;
-use crate::reader::resultscreen::common::ResultScreenInfo;
+use crate::reader::common::PlayKind;
+use crate::reader::resultscreen::common::{ResultScreenInfo, ResultScreenPerformance};
 use crate::reader::structs::Hit;
 use crate::reader::structs::State;
 use crate::Error;
@@ -35,6 +37,14 @@ impl<'a> ResultScreenReader<'a> {
         fn hits_geki() -> i16 => stable::memory::hits_geki,
         fn hits_katu() -> i16 => stable::memory::hits_katu,
         fn accuracy() -> f64 => stable::memory::accuracy,
+        fn mods() -> u32 => stable::memory::mods,
+        fn grade() -> Grade => stable::memory::grade,
+        fn date() -> i64 => stable::memory::date,
+        fn online_id() -> i64 => stable::memory::online_id,
+        fn beatmap_md5() -> String => stable::memory::beatmap_md5,
+        fn is_replay() -> bool => stable::memory::is_replay,
+        fn play_kind() -> PlayKind => stable::memory::play_kind,
+        fn performance() -> ResultScreenPerformance => stable::memory::performance,
         fn info() -> ResultScreenInfo => stable::memory::info,
     }
 }
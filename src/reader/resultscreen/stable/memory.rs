@@ -1,16 +1,21 @@
+use crate::db::binary::ticks_to_unix_millis;
 use crate::generate_offset_getter;
-use crate::reader::common::stable::memory::check_game_state;
-use crate::reader::common::GameMode;
-use crate::reader::common::GameState;
-use crate::reader::helpers::{calculate_accuracy, read_i16, read_i32, read_string};
-use crate::reader::resultscreen::common::ResultScreenInfo;
+use crate::reader::beatmap::stable::file::parsed_pp;
+use crate::reader::common::stable::memory::{game_state, play_kind as common_play_kind};
+use crate::reader::common::{GameMode, Grade, PlayKind};
+use crate::reader::helpers::{read_i16, read_i32, read_i64, read_string, read_u64};
+use crate::reader::resultscreen::common::{ResultScreenInfo, ResultScreenPerformance};
 use crate::reader::resultscreen::stable::offset::RESULT_SCREEN_OFFSET;
 use crate::reader::structs::{Hit, State};
+use crate::scoring::{calculate_accuracy, calculate_grade};
 use crate::Error;
 use rosu_mem::process::{Process, ProcessTraits};
 
+/// Removes the `DateTimeKind` bits of a .NET `DateTime` binary
+const DATE_TICKS_MASK: i64 = 0x3FFF_FFFF_FFFF_FFFF;
+
 pub fn result_screen_ptr(p: &Process, state: &mut State) -> Result<i32, Error> {
-    if check_game_state(p, state, GameState::ResultScreen)? {
+    if game_state(p, state)?.is_result_screen() {
         Ok(p.read_i32(state.addresses.rulesets - RESULT_SCREEN_OFFSET.ptr)?)
     } else {
         Err(Error::NotAvailable("Not in ResultScreen".to_string()))
@@ -50,6 +55,11 @@ generate_offset_getter! {
     score: i32 = read_i32(RESULT_SCREEN_OFFSET.score, result_screen_base);
     max_combo: i16 = read_i16(RESULT_SCREEN_OFFSET.max_combo, result_screen_base);
     mode: GameMode = read_i32(RESULT_SCREEN_OFFSET.mode, result_screen_base);
+    mods_xor_base: i32 = read_i32(RESULT_SCREEN_OFFSET.mods, result_screen_base);
+    mods_xor1: u64 = read_u64(RESULT_SCREEN_OFFSET.mods_xor, mods_xor_base);
+    mods_xor2: u64 = read_u64(RESULT_SCREEN_OFFSET.mods_xor2, mods_xor_base);
+    online_id: i64 = read_i64(RESULT_SCREEN_OFFSET.online_id, result_screen_base);
+    date_binary: i64 = read_i64(RESULT_SCREEN_OFFSET.date, result_screen_base);
     hits_300: i16 = read_i16(RESULT_SCREEN_OFFSET.hits._300, result_screen_base);
     hits_100: i16 = read_i16(RESULT_SCREEN_OFFSET.hits._100, result_screen_base);
     hits_50: i16 = read_i16(RESULT_SCREEN_OFFSET.hits._50, result_screen_base);
@@ -58,17 +68,72 @@ generate_offset_getter! {
     hits_katu: i16 = read_i16(RESULT_SCREEN_OFFSET.hits._katu, result_screen_base);
 }
 
+pub fn mods(p: &Process, state: &mut State) -> Result<u32, Error> {
+    let mods_xor1 = mods_xor1(p, state)?;
+    let mods_xor2 = mods_xor2(p, state)?;
+    Ok((mods_xor1 ^ mods_xor2) as u32)
+}
+
+pub fn grade(p: &Process, state: &mut State) -> Result<Grade, Error> {
+    calculate_grade(&mode(p, state)?, &hits(p, state)?, mods(p, state)?)
+}
+
+/// Unix time in ms the score was set
+pub fn date(p: &Process, state: &mut State) -> Result<i64, Error> {
+    Ok(ticks_to_unix_millis(
+        date_binary(p, state)? & DATE_TICKS_MASK,
+    ))
+}
+
+/// MD5 of the beatmap of the score, the result screen keeps it selected
+pub fn beatmap_md5(p: &Process, state: &mut State) -> Result<String, Error> {
+    crate::reader::beatmap::stable::memory::md5(p, state)
+}
+
+/// Same as [`ResultScreenInfo::is_replay`], autoplay and spectated plays are
+/// replays too
+pub fn is_replay(p: &Process, state: &mut State) -> Result<bool, Error> {
+    Ok(play_kind(p, state)? != PlayKind::Play)
+}
+
+pub fn play_kind(p: &Process, state: &mut State) -> Result<PlayKind, Error> {
+    let mods = mods(p, state)?;
+    common_play_kind(p, state, mods)
+}
+
+/// Parses the beatmap (cached in `state.beatmap_cache`) to compute the pp of
+/// the score
+pub fn performance(p: &Process, state: &mut State) -> Result<ResultScreenPerformance, Error> {
+    let b = parsed_pp(p, state)?;
+    ResultScreenPerformance::new(
+        &b,
+        mode(p, state)?,
+        &hits(p, state)?,
+        max_combo(p, state)?,
+        mods(p, state)?,
+    )
+}
+
+/// Only reads memory, the pp need the beatmap file, see [`performance`]
 pub fn info(p: &Process, state: &mut State) -> Result<ResultScreenInfo, Error> {
     let hits = hits(p, state)?;
     let mode = mode(p, state)?;
     let accuracy = calculate_accuracy(&mode, &hits)?;
+    let mods = mods(p, state)?;
     let base = result_screen_base(p, state)?;
+    let max_combo = p.read_i16(base + RESULT_SCREEN_OFFSET.max_combo)?;
     Ok(ResultScreenInfo {
         username: p.read_string(base + RESULT_SCREEN_OFFSET.username)?,
         mode,
-        max_combo: p.read_i16(base + RESULT_SCREEN_OFFSET.max_combo)?,
+        max_combo,
         score: p.read_i32(base + RESULT_SCREEN_OFFSET.score)?,
+        grade: calculate_grade(&mode, &hits, mods)?,
         hits,
         accuracy,
+        mods,
+        date: date(p, state)?,
+        online_id: online_id(p, state)?,
+        beatmap_md5: beatmap_md5(p, state)?,
+        kind: common_play_kind(p, state, mods)?,
     })
 }
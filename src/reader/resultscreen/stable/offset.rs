@@ -6,6 +6,11 @@ pub struct ResultScreenOffset {
     pub score: i32,
     pub max_combo: i32,
     pub mode: i32,
+    pub mods: i32,
+    pub mods_xor: i32,
+    pub mods_xor2: i32,
+    pub online_id: i32,
+    pub date: i32,
     pub hits: ResultScreenHitsOffset,
 }
 
@@ -17,6 +22,12 @@ pub(crate) const RESULT_SCREEN_OFFSET: ResultScreenOffset = ResultScreenOffset {
     score: 0x78,
     max_combo: 0x68,
     mode: 0x64,
+    mods: 0x1C,
+    mods_xor: 0xC,
+    mods_xor2: 0x8,
+    online_id: 0x4,
+    // .NET DateTime binary, the kind is stored in the 2 upper bits
+    date: 0xA0,
     hits: RESULT_SCREEN_HITS_OFFSET,
 };
 
@@ -0,0 +1,138 @@
+use std::fmt;
+
+use crate::reader::leaderboard::common::LeaderboardType;
+
+/// .NET `Keys` value as stored by osu!, the same as windows virtual key codes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OsuKey(pub i32);
+
+/// Names used by osu! in its config files
+const KEY_NAMES: [(i32, &str); 40] = [
+    (8, "Back"),
+    (9, "Tab"),
+    (13, "Enter"),
+    (20, "CapsLock"),
+    (27, "Escape"),
+    (32, "Space"),
+    (33, "PageUp"),
+    (34, "PageDown"),
+    (35, "End"),
+    (36, "Home"),
+    (37, "Left"),
+    (38, "Up"),
+    (39, "Right"),
+    (40, "Down"),
+    (45, "Insert"),
+    (46, "Delete"),
+    (106, "Multiply"),
+    (107, "Add"),
+    (109, "Subtract"),
+    (110, "Decimal"),
+    (111, "Divide"),
+    (160, "LeftShift"),
+    (161, "RightShift"),
+    (162, "LeftControl"),
+    (163, "RightControl"),
+    (164, "LeftAlt"),
+    (165, "RightAlt"),
+    (186, "OemSemicolon"),
+    (187, "OemPlus"),
+    (188, "OemComma"),
+    (189, "OemMinus"),
+    (190, "OemPeriod"),
+    (191, "OemQuestion"),
+    (192, "OemTilde"),
+    (219, "OemOpenBrackets"),
+    (220, "OemPipe"),
+    (221, "OemCloseBrackets"),
+    (222, "OemQuotes"),
+    (0, "None"),
+    (1, "LeftButton"),
+];
+
+impl OsuKey {
+    /// Name as written in `osu!.cfg`, e.g. `Z`, `D1`, `NumPad4` or `LeftShift`
+    pub fn name(&self) -> Option<String> {
+        match self.0 {
+            // A to Z
+            65..=90 => Some(char::from(self.0 as u8).to_string()),
+            48..=57 => Some(format!("D{}", self.0 - 48)),
+            96..=105 => Some(format!("NumPad{}", self.0 - 96)),
+            112..=135 => Some(format!("F{}", self.0 - 111)),
+            value => KEY_NAMES
+                .iter()
+                .find(|(code, _)| *code == value)
+                .map(|(_, name)| name.to_string()),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.trim();
+        let code = |prefix: &str, base: i32, range: std::ops::RangeInclusive<i32>| {
+            name.strip_prefix(prefix)
+                .and_then(|n| n.parse::<i32>().ok())
+                .filter(|n| range.contains(n))
+                .map(|n| Self(base + n))
+        };
+        if let [c @ b'A'..=b'Z'] = name.as_bytes() {
+            return Some(Self(*c as i32));
+        }
+        code("NumPad", 96, 0..=9)
+            .or_else(|| code("D", 48, 0..=9))
+            .or_else(|| code("F", 111, 1..=24))
+            .or_else(|| {
+                KEY_NAMES
+                    .iter()
+                    .find(|(_, n)| n.eq_ignore_ascii_case(name))
+                    .map(|(code, _)| Self(*code))
+            })
+    }
+}
+
+impl fmt::Display for OsuKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(&name),
+            None => write!(f, "Key({})", self.0),
+        }
+    }
+}
+
+/// Bindings of the osu!, taiko and catch keys
+///
+/// osu!mania is missing: its layouts depend on the key count and are not kept
+/// in the bindings dictionary, they are not read by this crate yet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyBindings {
+    pub osu_left: OsuKey,
+    pub osu_right: OsuKey,
+    pub taiko_inner_left: OsuKey,
+    pub taiko_inner_right: OsuKey,
+    pub taiko_outer_left: OsuKey,
+    pub taiko_outer_right: OsuKey,
+    pub catch_left: OsuKey,
+    pub catch_right: OsuKey,
+    pub catch_dash: OsuKey,
+}
+
+/// Volumes from 0 to 100, music and effects are scaled by the master volume
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AudioVolume {
+    pub master: i32,
+    pub music: i32,
+    pub effect: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Settings {
+    pub volume: AudioVolume,
+    /// universal offset in ms
+    pub offset: i32,
+    pub cursor_size: f64,
+    /// 0 to 100
+    pub background_dim: i32,
+    pub key_bindings: KeyBindings,
+    pub leaderboard_type: LeaderboardType,
+    /// folder name of the skin
+    pub skin: String,
+}
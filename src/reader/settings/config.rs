@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::reader::leaderboard::common::LeaderboardType;
+use crate::reader::settings::common::{AudioVolume, KeyBindings, OsuKey, Settings};
+use crate::reader::structs::State;
+use crate::reader::wine::{self, WinePrefix};
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Frame limiter of osu!, `FrameSync` in the config
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameSync {
+    VSync,
+    PowerSaving,
+    Limit120,
+    /// twice the refresh rate of the display
+    Limit2x,
+    Unlimited,
+    Custom,
+    Other(String),
+}
+
+impl FrameSync {
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "VSync" => Self::VSync,
+            "PowerSaving" => Self::PowerSaving,
+            "Limit120" => Self::Limit120,
+            "Limit2x" => Self::Limit2x,
+            "Unlimited" | "CompletelyUnlimited" => Self::Unlimited,
+            "Custom" => Self::Custom,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// Settings from `osu!.cfg` and `osu!.<username>.cfg`, nothing is read from
+/// memory so they are available when osu! is closed or its signatures can't
+/// be found, see [`OsuConfig::load`] and [`OsuConfig::from_process`]
+///
+/// Values of the user config override the ones of `osu!.cfg`, typed getters
+/// return `None` when the key is missing or invalid
+#[derive(Debug, Clone, Default)]
+pub struct OsuConfig {
+    /// osu! folder the files were loaded from, relative paths are joined to it
+    pub dir: Option<PathBuf>,
+    /// wine prefix of osu!, windows paths are translated with it
+    pub prefix: Option<WinePrefix>,
+    pub values: HashMap<String, String>,
+}
+
+impl OsuConfig {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+        Ok(Self::parse(&String::from_utf8_lossy(&bytes)))
+    }
+
+    /// Parses `Key = Value` lines, lines starting with `#` are comments
+    pub fn parse(content: &str) -> Self {
+        let mut config = Self::default();
+        config.extend(content);
+        config
+    }
+
+    fn extend(&mut self, content: &str) {
+        for line in content.lines() {
+            let line = line.trim_start_matches('\u{feff}').trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                self.values
+                    .insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    /// Loads `osu!.cfg` and the config of `username` from the osu! folder
+    ///
+    /// Without username, the most recently modified `osu!.*.cfg` is used
+    ///
+    /// This is the entry point when osu! is not running, e.g. with the install
+    /// folder of the user
+    pub fn load(dir: impl AsRef<Path>, username: Option<&str>) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        let user_path = match username {
+            Some(username) => Some(dir.join(format!("osu!.{username}.cfg"))),
+            None => latest_user_config(dir)?,
+        };
+
+        let mut config = Self {
+            dir: Some(dir.to_path_buf()),
+            ..Default::default()
+        };
+        let mut found = false;
+        for path in std::iter::once(dir.join("osu!.cfg")).chain(user_path) {
+            if path.is_file() {
+                let bytes = std::fs::read(&path)?;
+                config.extend(&String::from_utf8_lossy(&bytes));
+                found = true;
+            }
+        }
+
+        if !found {
+            return Err(Error::NotAvailable(format!(
+                "No osu! config file in {}",
+                dir.display()
+            )));
+        }
+        Ok(config)
+    }
+
+    /// Loads the configs next to the executable of the osu! process
+    ///
+    /// Only the paths kept in `state` are used, a `State::default()` works
+    /// when the signatures of osu! can't be found. The wine prefix of osu! is
+    /// kept to translate the paths of the config
+    pub fn from_process(
+        p: &Process,
+        state: &mut State,
+        username: Option<&str>,
+    ) -> Result<Self, Error> {
+        let dir = wine::executable_dir(p, state).ok_or_else(|| {
+            Error::NotAvailable("Executable directory of osu! is unknown".to_string())
+        })?;
+        Ok(Self {
+            prefix: wine::prefix(p, state),
+            ..Self::load(dir, username)?
+        })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    pub fn get_i32(&self, key: &str) -> Option<i32> {
+        self.get(key)?.parse().ok()
+    }
+
+    pub fn get_f64(&self, key: &str) -> Option<f64> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// osu! writes `1` and `0` for booleans
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.get(key)? {
+            "1" | "True" | "true" => Some(true),
+            "0" | "False" | "false" => Some(false),
+            _ => None,
+        }
+    }
+
+    pub fn get_key(&self, key: &str) -> Option<OsuKey> {
+        OsuKey::from_name(self.get(key)?)
+    }
+
+    pub fn username(&self) -> Option<&str> {
+        self.get("Username").filter(|name| !name.is_empty())
+    }
+
+    /// `BeatmapDirectory` as written, `Songs` by default
+    pub fn beatmap_directory(&self) -> &str {
+        self.get("BeatmapDirectory")
+            .filter(|dir| !dir.is_empty())
+            .unwrap_or("Songs")
+    }
+
+    /// Songs folder, joined to the osu! folder when relative
+    ///
+    /// Windows paths like `D:\Songs` are translated with `prefix`, without it
+    /// only native paths are right
+    pub fn songs_path(&self) -> PathBuf {
+        let directory = self.beatmap_directory();
+        if let Some(prefix) = &self.prefix {
+            return prefix.translate(directory, self.dir.as_deref());
+        }
+        let path = PathBuf::from(directory);
+        match &self.dir {
+            Some(dir) if path.is_relative() => dir.join(path),
+            _ => path,
+        }
+    }
+
+    pub fn skin(&self) -> Option<&str> {
+        self.get("Skin")
+    }
+
+    pub fn volume(&self) -> AudioVolume {
+        AudioVolume {
+            master: self.get_i32("VolumeUniversal").unwrap_or(100),
+            music: self.get_i32("VolumeMusic").unwrap_or(80),
+            effect: self.get_i32("VolumeEffect").unwrap_or(80),
+        }
+    }
+
+    /// Universal offset in ms
+    pub fn offset(&self) -> i32 {
+        self.get_i32("Offset").unwrap_or_default()
+    }
+
+    pub fn frame_sync(&self) -> Option<FrameSync> {
+        self.get("FrameSync").map(FrameSync::parse)
+    }
+
+    /// FPS limit when it doesn't depend on the display
+    pub fn fps_limit(&self) -> Option<i32> {
+        match self.frame_sync()? {
+            FrameSync::PowerSaving => Some(60),
+            FrameSync::Limit120 => Some(120),
+            FrameSync::Custom => self.get_i32("CustomFrameLimit"),
+            _ => None,
+        }
+    }
+
+    pub fn leaderboard_type(&self) -> LeaderboardType {
+        match self.get("RankType") {
+            Some("Local") => LeaderboardType::Local,
+            Some("Top") => LeaderboardType::Global,
+            Some("SelectedMods") => LeaderboardType::SelectedMods,
+            Some("Friends") => LeaderboardType::Friends,
+            Some("Country") => LeaderboardType::Country,
+            _ => LeaderboardType::Unknown,
+        }
+    }
+
+    /// Key bindings, osu! defaults are used for missing keys
+    pub fn key_bindings(&self) -> KeyBindings {
+        let key = |name: &str, default: &str| {
+            self.get_key(name)
+                .or_else(|| OsuKey::from_name(default))
+                .unwrap_or_default()
+        };
+        KeyBindings {
+            osu_left: key("keyOsuLeft", "Z"),
+            osu_right: key("keyOsuRight", "X"),
+            taiko_inner_left: key("keyTaikoInnerLeft", "X"),
+            taiko_inner_right: key("keyTaikoInnerRight", "C"),
+            taiko_outer_left: key("keyTaikoOuterLeft", "Z"),
+            taiko_outer_right: key("keyTaikoOuterRight", "V"),
+            catch_left: key("keyFruitsLeft", "Left"),
+            catch_right: key("keyFruitsRight", "Right"),
+            catch_dash: key("keyFruitsDash", "LeftShift"),
+        }
+    }
+
+    /// Same settings as the ones read from memory
+    pub fn settings(&self) -> Settings {
+        Settings {
+            volume: self.volume(),
+            offset: self.offset(),
+            cursor_size: self.get_f64("CursorSize").unwrap_or(1.0),
+            background_dim: self.get_i32("DimLevel").unwrap_or(30),
+            key_bindings: self.key_bindings(),
+            leaderboard_type: self.leaderboard_type(),
+            skin: self.skin().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+fn latest_user_config(dir: &Path) -> Result<Option<PathBuf>, Error> {
+    let mut latest = None;
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name == "osu!.cfg" || !name.starts_with("osu!.") || !name.ends_with(".cfg") {
+            continue;
+        }
+        let modified = entry.metadata()?.modified()?;
+        if latest.as_ref().is_none_or(|(time, _)| modified > *time) {
+            latest = Some((modified, entry.path()));
+        }
+    }
+    Ok(latest.map(|(_, path)| path))
+}
@@ -0,0 +1,41 @@
+pub mod common;
+pub mod config;
+pub mod stable;
+
+use crate::impl_osu_accessor;
+use crate::reader::common::OsuClientKind;
+use crate::reader::leaderboard::common::LeaderboardType;
+use crate::reader::settings::common::{AudioVolume, KeyBindings, Settings};
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// osu! options, read from memory so they are up to date while osu! runs
+pub struct SettingsReader<'a> {
+    pub process: &'a Process,
+    pub state: &'a mut State,
+    pub osu_type: OsuClientKind,
+}
+
+impl<'a> SettingsReader<'a> {
+    pub fn new(p: &'a Process, state: &'a mut State, osu_type: OsuClientKind) -> Self {
+        Self {
+            process: p,
+            state,
+            osu_type,
+        }
+    }
+    impl_osu_accessor! {
+        fn volume_master() -> i32 => stable::memory::volume_master,
+        fn volume_music() -> i32 => stable::memory::volume_music,
+        fn volume_effect() -> i32 => stable::memory::volume_effect,
+        fn volume() -> AudioVolume => stable::memory::volume,
+        fn offset() -> i32 => stable::memory::offset,
+        fn cursor_size() -> f64 => stable::memory::cursor_size,
+        fn background_dim() -> i32 => stable::memory::background_dim,
+        fn key_bindings() -> KeyBindings => stable::memory::key_bindings,
+        fn leaderboard_type() -> LeaderboardType => stable::memory::leaderboard_type,
+        fn skin() -> String => stable::memory::skin,
+        fn info() -> Settings => stable::memory::info,
+    }
+}
@@ -0,0 +1,219 @@
+use crate::reader::leaderboard::common::LeaderboardType;
+use crate::reader::settings::common::{AudioVolume, KeyBindings, OsuKey, Settings};
+use crate::reader::settings::config::OsuConfig;
+use crate::reader::settings::stable::offset::{bindings, names, SETTINGS_OFFSET};
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::{Process, ProcessTraits};
+
+pub fn settings_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    Ok(p.read_i32(state.addresses.settings + SETTINGS_OFFSET.ptr)?)
+}
+
+pub fn config_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let settings_addr = settings_addr(p, state)?;
+    let addr = p.read_i32(settings_addr + SETTINGS_OFFSET.config)?;
+    if addr == 0 {
+        return Err(Error::NotAvailable("Config not loaded".to_string()));
+    }
+    Ok(addr)
+}
+
+pub fn bindings_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let settings_addr = settings_addr(p, state)?;
+    let addr = p.read_i32(settings_addr + SETTINGS_OFFSET.bindings)?;
+    if addr == 0 {
+        return Err(Error::NotAvailable("Key bindings not loaded".to_string()));
+    }
+    Ok(addr)
+}
+
+/// Bindables of the settings named `names`, in the same order, `None` when
+/// osu! has no such setting
+///
+/// The dictionary is walked once for all names
+fn find_bindables<const N: usize>(
+    p: &Process,
+    config_addr: i32,
+    names: [&str; N],
+) -> Result<[Option<i32>; N], Error> {
+    let dictionary = &SETTINGS_OFFSET.dictionary;
+    let entries = p.read_i32(config_addr + dictionary.entries)?;
+    let count = p.read_i32(config_addr + dictionary.count)?;
+    let mut found = [None; N];
+    for i in 0..count.max(0) {
+        let entry = entries + 0x8 + i * dictionary.entry_size;
+        // removed entry
+        if p.read_i32(entry + dictionary.key)? == 0 {
+            continue;
+        }
+        let key = p.read_string(entry + dictionary.key)?;
+        if let Some(index) = names.iter().position(|name| *name == key) {
+            found[index] = Some(p.read_i32(entry + dictionary.value)?);
+            if found.iter().all(Option::is_some) {
+                break;
+            }
+        }
+    }
+    Ok(found)
+}
+
+fn required(bindable: Option<i32>, name: &str) -> Result<i32, Error> {
+    bindable.ok_or_else(|| Error::NotAvailable(format!("No setting named {name}")))
+}
+
+fn find_bindable(p: &Process, config_addr: i32, name: &str) -> Result<i32, Error> {
+    let [bindable] = find_bindables(p, config_addr, [name])?;
+    required(bindable, name)
+}
+
+fn read_int(p: &Process, bindable: i32) -> Result<i32, Error> {
+    Ok(p.read_i32(bindable + SETTINGS_OFFSET.bindable.int)?)
+}
+
+fn read_double(p: &Process, bindable: i32) -> Result<f64, Error> {
+    Ok(p.read_f64(bindable + SETTINGS_OFFSET.bindable.double)?)
+}
+
+fn read_string(p: &Process, bindable: i32) -> Result<String, Error> {
+    Ok(p.read_string(bindable + SETTINGS_OFFSET.bindable.string)?)
+}
+
+// generate getters of named settings, same idea as generate_offset_getter
+macro_rules! generate_setting_getter {
+    (
+        $( $fn_name:ident : $ret_ty:ty = $read_fn:ident ( $name:expr ); )*
+    ) => {
+        $(
+            pub fn $fn_name(p: &Process, state: &mut State) -> Result<$ret_ty, Error> {
+                let config_addr = config_addr(p, state)?;
+                let bindable = find_bindable(p, config_addr, $name)?;
+                Ok(<$ret_ty>::from($read_fn(p, bindable)?))
+            }
+        )*
+    };
+}
+
+generate_setting_getter! {
+    volume_master: i32 = read_int(names::VOLUME_MASTER);
+    volume_music: i32 = read_int(names::VOLUME_MUSIC);
+    volume_effect: i32 = read_int(names::VOLUME_EFFECT);
+    offset: i32 = read_int(names::OFFSET);
+    cursor_size: f64 = read_double(names::CURSOR_SIZE);
+    background_dim: i32 = read_int(names::BACKGROUND_DIM);
+    leaderboard_type: LeaderboardType = read_int(names::LEADERBOARD_TYPE);
+    skin: String = read_string(names::SKIN);
+}
+
+pub fn volume(p: &Process, state: &mut State) -> Result<AudioVolume, Error> {
+    let config_addr = config_addr(p, state)?;
+    let [master, music, effect] = find_bindables(
+        p,
+        config_addr,
+        [
+            names::VOLUME_MASTER,
+            names::VOLUME_MUSIC,
+            names::VOLUME_EFFECT,
+        ],
+    )?;
+    let volume = |bindable, name| read_int(p, required(bindable, name)?);
+    Ok(AudioVolume {
+        master: volume(master, names::VOLUME_MASTER)?,
+        music: volume(music, names::VOLUME_MUSIC)?,
+        effect: volume(effect, names::VOLUME_EFFECT)?,
+    })
+}
+
+/// Keys bound to the `Bindings` values in `values`, in the same order, `None`
+/// when osu! has no such binding
+///
+/// The dictionary is walked once for all values
+fn find_keys<const N: usize>(
+    p: &Process,
+    bindings_addr: i32,
+    values: [i32; N],
+) -> Result<[Option<OsuKey>; N], Error> {
+    let dictionary = &SETTINGS_OFFSET.bindings_dictionary;
+    let entries = p.read_i32(bindings_addr + dictionary.entries)?;
+    let count = p.read_i32(bindings_addr + dictionary.count)?;
+    let mut found = [None; N];
+    for i in 0..count.max(0) {
+        let entry = entries + 0x8 + i * dictionary.entry_size;
+        // removed entry
+        if p.read_i32(entry + dictionary.hash_code)? < 0 {
+            continue;
+        }
+        let key = p.read_i32(entry + dictionary.key)?;
+        if let Some(index) = values.iter().position(|value| *value == key) {
+            found[index] = Some(OsuKey(p.read_i32(entry + dictionary.value)?));
+            if found.iter().all(Option::is_some) {
+                break;
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// Key bindings from the bindings dictionary osu! uses while running, the
+/// config files are read instead when one of them is missing
+pub fn key_bindings(p: &Process, state: &mut State) -> Result<KeyBindings, Error> {
+    let bindings_addr = bindings_addr(p, state)?;
+    let keys = find_keys(
+        p,
+        bindings_addr,
+        [
+            bindings::OSU_LEFT,
+            bindings::OSU_RIGHT,
+            bindings::TAIKO_INNER_LEFT,
+            bindings::TAIKO_INNER_RIGHT,
+            bindings::TAIKO_OUTER_LEFT,
+            bindings::TAIKO_OUTER_RIGHT,
+            bindings::FRUITS_LEFT,
+            bindings::FRUITS_RIGHT,
+            bindings::FRUITS_DASH,
+        ],
+    )?;
+    if keys.iter().any(Option::is_none) {
+        // other `Bindings` values on this osu! build, the saved ones are used
+        return Ok(OsuConfig::from_process(p, state, None)?.key_bindings());
+    }
+    let key = |i: usize| keys[i].unwrap_or_default();
+    Ok(KeyBindings {
+        osu_left: key(0),
+        osu_right: key(1),
+        taiko_inner_left: key(2),
+        taiko_inner_right: key(3),
+        taiko_outer_left: key(4),
+        taiko_outer_right: key(5),
+        catch_left: key(6),
+        catch_right: key(7),
+        catch_dash: key(8),
+    })
+}
+
+pub fn info(p: &Process, state: &mut State) -> Result<Settings, Error> {
+    let config_addr = config_addr(p, state)?;
+    let [offset, cursor_size, background_dim, leaderboard_type, skin] = find_bindables(
+        p,
+        config_addr,
+        [
+            names::OFFSET,
+            names::CURSOR_SIZE,
+            names::BACKGROUND_DIM,
+            names::LEADERBOARD_TYPE,
+            names::SKIN,
+        ],
+    )?;
+    Ok(Settings {
+        volume: volume(p, state)?,
+        offset: read_int(p, required(offset, names::OFFSET)?)?,
+        cursor_size: read_double(p, required(cursor_size, names::CURSOR_SIZE)?)?,
+        background_dim: read_int(p, required(background_dim, names::BACKGROUND_DIM)?)?,
+        key_bindings: key_bindings(p, state)?,
+        leaderboard_type: LeaderboardType::from(read_int(
+            p,
+            required(leaderboard_type, names::LEADERBOARD_TYPE)?,
+        )?),
+        skin: read_string(p, required(skin, names::SKIN)?)?,
+    })
+}
@@ -0,0 +1,95 @@
+pub struct SettingsOffset {
+    /// config object, from the settings address
+    pub ptr: i32,
+    /// `Dictionary<string, Bindable>` of every setting, from the config object
+    pub config: i32,
+    /// `Dictionary<Bindings, Keys>` of the key bindings, from the config object
+    pub bindings: i32,
+    pub dictionary: DictionaryOffset,
+    pub bindings_dictionary: DictionaryOffset,
+    pub bindable: BindableOffset,
+}
+
+// Settings are looked up by the name osu! writes in its config files and key
+// bindings by their `Bindings` value instead of one offset per setting, only
+// `config` and `bindings` depend on the osu! build
+pub(crate) const SETTINGS_OFFSET: SettingsOffset = SettingsOffset {
+    ptr: 0x8,
+    config: 0x8,
+    bindings: 0xC,
+    dictionary: DICTIONARY_OFFSET,
+    bindings_dictionary: BINDINGS_DICTIONARY_OFFSET,
+    bindable: BINDABLE_OFFSET,
+};
+
+/// .NET Framework `Dictionary<TKey, TValue>`
+pub struct DictionaryOffset {
+    /// `Entry[]`
+    pub entries: i32,
+    /// used entries, removed ones included
+    pub count: i32,
+    pub entry_size: i32,
+    /// negative for removed entries
+    pub hash_code: i32,
+    pub key: i32,
+    pub value: i32,
+}
+
+/// With reference keys and values
+pub(crate) const DICTIONARY_OFFSET: DictionaryOffset = DictionaryOffset {
+    entries: 0x8,
+    count: 0x1C,
+    // references come first in the entry, then hash code and next
+    entry_size: 0x10,
+    hash_code: 0x8,
+    key: 0x0,
+    value: 0x4,
+};
+
+/// With enum keys and values, the entry keeps its declaration order
+pub(crate) const BINDINGS_DICTIONARY_OFFSET: DictionaryOffset = DictionaryOffset {
+    entries: 0x8,
+    count: 0x1C,
+    entry_size: 0x10,
+    hash_code: 0x0,
+    key: 0x8,
+    value: 0xC,
+};
+
+/// Where the value is in a bindable
+pub struct BindableOffset {
+    pub int: i32,
+    pub double: i32,
+    pub string: i32,
+}
+
+pub(crate) const BINDABLE_OFFSET: BindableOffset = BindableOffset {
+    int: 0xC,
+    double: 0x4,
+    string: 0x4,
+};
+
+/// Values of the osu! `Bindings` enum, the keys of the bindings dictionary
+pub(crate) mod bindings {
+    pub const OSU_LEFT: i32 = 1;
+    pub const OSU_RIGHT: i32 = 2;
+    pub const TAIKO_INNER_LEFT: i32 = 4;
+    pub const TAIKO_INNER_RIGHT: i32 = 5;
+    pub const TAIKO_OUTER_LEFT: i32 = 6;
+    pub const TAIKO_OUTER_RIGHT: i32 = 7;
+    pub const FRUITS_LEFT: i32 = 8;
+    pub const FRUITS_RIGHT: i32 = 9;
+    pub const FRUITS_DASH: i32 = 10;
+}
+
+/// Names of the settings in the config dictionary, the same as in `osu!.cfg`
+pub(crate) mod names {
+    pub const VOLUME_MASTER: &str = "VolumeUniversal";
+    pub const VOLUME_MUSIC: &str = "VolumeMusic";
+    pub const VOLUME_EFFECT: &str = "VolumeEffect";
+    pub const OFFSET: &str = "Offset";
+    pub const CURSOR_SIZE: &str = "CursorSize";
+    pub const BACKGROUND_DIM: &str = "DimLevel";
+    pub const LEADERBOARD_TYPE: &str = "RankType";
+    pub const SKIN: &str = "Skin";
+}
@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkinColour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// 255 when not given
+    pub a: u8,
+}
+
+impl SkinColour {
+    /// Parses `r,g,b` or `r,g,b,a`
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.split(',').map(|part| part.trim().parse::<u8>());
+        let r = parts.next()?.ok()?;
+        let g = parts.next()?.ok()?;
+        let b = parts.next()?.ok()?;
+        let a = match parts.next() {
+            Some(a) => a.ok()?,
+            None => 255,
+        };
+        Some(Self { r, g, b, a })
+    }
+
+    /// `#rrggbb`, the alpha is left out
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// A `[Mania]` section, skins have one per key count
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ManiaConfig {
+    pub keys: u32,
+    /// every other setting as written in the file
+    pub values: BTreeMap<String, String>,
+}
+
+impl ManiaConfig {
+    pub fn value(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(String::as_str)
+    }
+
+    /// e.g. `Colour1` or `ColourLight1`
+    pub fn colour(&self, key: &str) -> Option<SkinColour> {
+        SkinColour::parse(self.value(key)?)
+    }
+}
+
+/// The parts of `skin.ini` useful to theme something after the skin
+///
+/// Like osu!, unknown sections and invalid lines are skipped
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SkinIni {
+    pub name: String,
+    pub author: String,
+    /// `latest` or a version like `2.7`, `1.0` when not given
+    pub version: String,
+    /// `Combo1` to `Combo8` in order
+    pub combo_colours: Vec<SkinColour>,
+    /// every other colour of `[Colours]`, e.g. `SliderBorder`
+    pub colours: BTreeMap<String, SkinColour>,
+    pub mania: Vec<ManiaConfig>,
+}
+
+impl SkinIni {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let bytes = std::fs::read(path)?;
+        Ok(Self::parse(&String::from_utf8_lossy(&bytes)))
+    }
+
+    pub fn parse(content: &str) -> Self {
+        let mut ini = Self {
+            version: "1.0".to_string(),
+            ..Default::default()
+        };
+        let mut combo_colours = BTreeMap::new();
+        let mut section = String::new();
+
+        for line in content.lines() {
+            let line = line.trim_start_matches('\u{feff}');
+            let line = line.split("//").next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = name.trim().to_string();
+                if section == "Mania" {
+                    ini.mania.push(ManiaConfig::default());
+                }
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+
+            match section.as_str() {
+                "General" => match key {
+                    "Name" => ini.name = value.to_string(),
+                    "Author" => ini.author = value.to_string(),
+                    "Version" => ini.version = value.to_string(),
+                    _ => {}
+                },
+                "Colours" => {
+                    let Some(colour) = SkinColour::parse(value) else {
+                        continue;
+                    };
+                    match key.strip_prefix("Combo").and_then(|i| i.parse::<u8>().ok()) {
+                        Some(index @ 1..=8) => {
+                            combo_colours.insert(index, colour);
+                        }
+                        _ => {
+                            ini.colours.insert(key.to_string(), colour);
+                        }
+                    }
+                }
+                "Mania" => {
+                    let Some(mania) = ini.mania.last_mut() else {
+                        continue;
+                    };
+                    if key == "Keys" {
+                        mania.keys = value.parse().unwrap_or_default();
+                    } else {
+                        mania.values.insert(key.to_string(), value.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        ini.combo_colours = combo_colours.into_values().collect();
+        ini
+    }
+
+    pub fn colour(&self, key: &str) -> Option<SkinColour> {
+        self.colours.get(key).copied()
+    }
+
+    /// Config for a key count, `None` when the skin uses the default one
+    pub fn mania_config(&self, keys: u32) -> Option<&ManiaConfig> {
+        self.mania.iter().find(|m| m.keys == keys)
+    }
+}
@@ -0,0 +1,32 @@
+pub mod common;
+pub mod stable;
+
+use std::path::PathBuf;
+
+use crate::impl_osu_accessor;
+use crate::reader::common::OsuClientKind;
+use crate::reader::skin::common::SkinIni;
+use crate::reader::structs::State;
+use crate::Error;
+use rosu_mem::process::Process;
+
+pub struct SkinReader<'a> {
+    pub process: &'a Process,
+    pub state: &'a mut State,
+    pub osu_type: OsuClientKind,
+}
+
+impl<'a> SkinReader<'a> {
+    pub fn new(p: &'a Process, state: &'a mut State, osu_type: OsuClientKind) -> Self {
+        Self {
+            process: p,
+            state,
+            osu_type,
+        }
+    }
+    impl_osu_accessor! {
+        fn folder() -> String => stable::memory::folder,
+        fn path() -> PathBuf => stable::memory::path,
+        fn ini() -> SkinIni => stable::memory::ini,
+    }
+}
@@ -0,0 +1,33 @@
+use std::path::PathBuf;
+
+use crate::generate_offset_getter;
+use crate::reader::helpers::read_string;
+use crate::reader::skin::common::SkinIni;
+use crate::reader::skin::stable::offset::SKIN_OFFSET;
+use crate::reader::structs::State;
+use crate::reader::wine;
+use crate::Error;
+use rosu_mem::process::{Process, ProcessTraits};
+
+pub fn skin_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let skin_ptr = p.read_i32(state.addresses.skin + SKIN_OFFSET.ptr)?;
+    Ok(p.read_i32(skin_ptr)?)
+}
+
+generate_offset_getter! {
+    folder: String = read_string(SKIN_OFFSET.folder, skin_addr);
+}
+
+/// Returns the absolute path of the current skin, from the osu! directory
+pub fn path(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
+    let folder = folder(p, state)?;
+    let Some(executable_dir) = wine::executable_dir(p, state) else {
+        return Err(Error::NotAvailable("osu! directory not found".to_string()));
+    };
+    Ok(executable_dir.join("Skins").join(folder))
+}
+
+/// Parses the `skin.ini` of the current skin, the default skin has none
+pub fn ini(p: &Process, state: &mut State) -> Result<SkinIni, Error> {
+    SkinIni::from_path(path(p, state)?.join("skin.ini"))
+}
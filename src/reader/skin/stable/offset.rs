@@ -0,0 +1,9 @@
+pub struct SkinOffset {
+    pub ptr: i32,
+    pub folder: i32,
+}
+
+pub(crate) const SKIN_OFFSET: SkinOffset = SkinOffset {
+    ptr: 0x7,
+    folder: 0x44,
+};
@@ -2,6 +2,8 @@
 #[cfg(feature = "parallel-read")]
 use rayon::prelude::*;
 
+use crate::reader::beatmap::cache::BeatmapCache;
+use crate::reader::wine::ProcessPaths;
 use crate::Error;
 use rosu_mem::{
     process::{Process, ProcessTraits},
@@ -24,9 +26,13 @@ pub struct StaticAddresses {
     pub ig_time_base: i32,
     pub settings: i32,
     pub user_profile: i32,
+    // optional signatures, `None` when not found, only the getters using them
+    // return an error
+    pub can_run_slowly: Option<i32>,
+    pub spectating_user: Option<i32>,
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Hit {
     pub _geki: i16,
     pub _300: i16,
@@ -49,6 +55,8 @@ pub(crate) struct SignatureBase {
     ig_time_base_sig: &'static str,
     settings_sig: &'static str,
     user_profile_sig: &'static str,
+    can_run_slowly_sig: &'static str,
+    spectating_user_sig: &'static str,
 }
 
 // fixing some outdated signatures
@@ -68,6 +76,8 @@ pub(crate) const SIGNATURES: SignatureBase = SignatureBase {
     ig_time_base_sig: "A1 ?? ?? ?? ?? 89 46 04 8B D6 E8",
     settings_sig: "83 E0 20 85 C0 7E 2F",
     user_profile_sig: "FF 15 ?? ?? ?? ?? A1 ?? ?? ?? ?? 8B 48 54 33 D2",
+    can_run_slowly_sig: "55 8B EC 80 3D ?? ?? ?? ?? 00 75 26 80 3D",
+    spectating_user_sig: "8B 0D ?? ?? ?? ?? 85 C0 74 05 8B 50 30",
 };
 
 impl StaticAddresses {
@@ -105,6 +115,10 @@ impl StaticAddresses {
             ("settings", SIGNATURES.settings_sig),
             ("user_profile", SIGNATURES.user_profile_sig),
         ];
+        let optional_signatures = [
+            ("can_run_slowly", SIGNATURES.can_run_slowly_sig),
+            ("spectating_user", SIGNATURES.spectating_user_sig),
+        ];
 
         let results: HashMap<&str, i32> = signatures
             .par_iter()
@@ -114,6 +128,10 @@ impl StaticAddresses {
                 Ok::<_, Error>((*name, addr))
             })
             .collect::<Result<_, Error>>()?;
+        let optional: HashMap<&str, Option<i32>> = optional_signatures
+            .par_iter()
+            .map(|(name, sig)| (*name, read_optional_signature(p, sig)))
+            .collect();
 
         println!("Time taken: {:?}", start.elapsed());
 
@@ -129,6 +147,8 @@ impl StaticAddresses {
             ig_time_base: results["ig_time_base"],
             settings: results["settings"],
             user_profile: results["user_profile"],
+            can_run_slowly: optional["can_run_slowly"],
+            spectating_user: optional["spectating_user"],
         })
     }
 
@@ -147,6 +167,10 @@ impl StaticAddresses {
             ("settings", SIGNATURES.settings_sig),
             ("user_profile", SIGNATURES.user_profile_sig),
         ];
+        let optional_signatures = [
+            ("can_run_slowly", SIGNATURES.can_run_slowly_sig),
+            ("spectating_user", SIGNATURES.spectating_user_sig),
+        ];
 
         let mut results = HashMap::new();
         for (name, sig) in signatures.iter() {
@@ -154,6 +178,10 @@ impl StaticAddresses {
             let addr = p.read_signature(&signature)?;
             results.insert(*name, addr);
         }
+        let mut optional = HashMap::new();
+        for (name, sig) in optional_signatures.iter() {
+            optional.insert(*name, read_optional_signature(p, sig));
+        }
 
         println!("Time taken: {:?}", start.elapsed());
 
@@ -169,11 +197,33 @@ impl StaticAddresses {
             ig_time_base: results["ig_time_base"],
             settings: results["settings"],
             user_profile: results["user_profile"],
+            can_run_slowly: optional["can_run_slowly"],
+            spectating_user: optional["spectating_user"],
         })
     }
 }
 
+/// Scans a signature only needed by some getters, a failed scan gives `None`
+/// instead of failing every reader
+fn read_optional_signature(p: &Process, sig: &str) -> Option<i32> {
+    let signature = Signature::from_str(sig).ok()?;
+    match p.read_signature(&signature) {
+        Ok(addr) => Some(addr),
+        Err(e) => {
+            println!("Optional signature {sig} not found: {e}");
+            None
+        }
+    }
+}
+
+/// Address of an optional signature, `Error::NotAvailable` when it wasn't found
+pub(crate) fn optional_address(addr: Option<i32>, name: &str) -> Result<i32, Error> {
+    addr.ok_or_else(|| Error::NotAvailable(format!("{name} signature not found")))
+}
+
 #[derive(Default, Clone)]
 pub struct State {
     pub addresses: StaticAddresses,
+    pub beatmap_cache: BeatmapCache,
+    pub paths: ProcessPaths,
 }
@@ -0,0 +1,157 @@
+use crate::reader::common::{CommonReader, OsuClientKind};
+use crate::reader::gameplay::common::GameplayInfo;
+use crate::reader::gameplay::GameplayReader;
+use crate::reader::instances::{attach_all, command_line};
+use crate::reader::structs::State;
+use crate::reader::tourney::TourneyReader;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// An attached osu! process with its own static addresses
+pub struct TourneyInstance {
+    pub process: Process,
+    pub state: State,
+    /// slot given by the manager to its clients, `None` for the manager or
+    /// when the command line can't be read
+    pub slot: Option<usize>,
+}
+
+impl TourneyInstance {
+    pub fn new(process: Process, state: State) -> Self {
+        let slot = command_line(&process)
+            .ok()
+            .and_then(|args| spectate_client_slot(&args));
+        Self {
+            process,
+            state,
+            slot,
+        }
+    }
+
+    pub fn common(&mut self) -> CommonReader<'_> {
+        CommonReader::new(&self.process, &mut self.state, OsuClientKind::Stable)
+    }
+
+    pub fn gameplay(&mut self) -> GameplayReader<'_> {
+        GameplayReader::new(&self.process, &mut self.state, OsuClientKind::Stable)
+    }
+
+    pub fn tourney(&mut self) -> TourneyReader<'_> {
+        TourneyReader::new(&self.process, &mut self.state, OsuClientKind::Stable)
+    }
+}
+
+/// Slot of a tourney client from its arguments, the manager starts each
+/// client with `-spectateclient <slot>`
+pub fn spectate_client_slot<S: AsRef<str>>(args: &[S]) -> Option<usize> {
+    args.windows(2)
+        .find(|pair| pair[0].as_ref().eq_ignore_ascii_case("-spectateclient"))
+        .and_then(|pair| pair[1].as_ref().parse().ok())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourneySide {
+    Left,
+    Right,
+}
+
+impl TourneySide {
+    /// The first `team_size` slots are the left team
+    pub fn from_slot(slot: usize, team_size: usize) -> Self {
+        if slot < team_size {
+            Self::Left
+        } else {
+            Self::Right
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TourneyClientInfo {
+    /// 0-based slot of the client, see [`TourneyInstance::slot`]
+    pub slot: usize,
+    pub side: TourneySide,
+    /// `Err` while the client is not playing
+    pub gameplay: Result<GameplayInfo, Error>,
+}
+
+/// The tournament manager and its clients
+pub struct Tourney {
+    pub manager: TourneyInstance,
+    /// sorted by slot, clients without a known slot come last
+    pub clients: Vec<TourneyInstance>,
+}
+
+impl Tourney {
+    /// Attaches to every osu! instance, fails when no tourney manager runs
+    pub fn attach() -> Result<Self, Error> {
+        Self::from_instances(attach_all()?)
+    }
+
+    pub fn from_instances(instances: Vec<(State, Process)>) -> Result<Self, Error> {
+        let mut manager = None;
+        let mut clients = Vec::new();
+        for (state, process) in instances {
+            let mut instance = TourneyInstance::new(process, state);
+            if manager.is_none() && instance.tourney().is_manager()? {
+                manager = Some(instance);
+            } else {
+                clients.push(instance);
+            }
+        }
+        let manager =
+            manager.ok_or_else(|| Error::NotAvailable("No tourney manager running".to_string()))?;
+        // pids don't follow the launch order, mostly on windows
+        clients.sort_by_key(|client| client.slot.unwrap_or(usize::MAX));
+        Ok(Self { manager, clients })
+    }
+
+    /// Clients per team
+    pub fn team_size(&self) -> usize {
+        Self::team_size_of(self.clients.len())
+    }
+
+    /// Clients per team for `clients` clients, the left team gets the extra one
+    pub fn team_size_of(clients: usize) -> usize {
+        clients.div_ceil(2)
+    }
+
+    /// Side of the client in `slot`
+    pub fn side(&self, slot: usize) -> TourneySide {
+        TourneySide::from_slot(slot, self.team_size())
+    }
+
+    pub fn manager(&mut self) -> TourneyReader<'_> {
+        self.manager.tourney()
+    }
+
+    pub fn client_infos(&mut self) -> Vec<TourneyClientInfo> {
+        let team_size = self.team_size();
+        self.clients
+            .iter_mut()
+            .enumerate()
+            .map(|(index, client)| {
+                let slot = client.slot.unwrap_or(index);
+                TourneyClientInfo {
+                    slot,
+                    side: TourneySide::from_slot(slot, team_size),
+                    gameplay: client.gameplay().info(),
+                }
+            })
+            .collect()
+    }
+
+    /// Live score of each team summed from its clients, clients not playing
+    /// count as 0
+    pub fn live_scores(&mut self) -> (i64, i64) {
+        self.client_infos()
+            .iter()
+            .fold((0, 0), |(left, right), client| {
+                let score = client.gameplay.as_ref().map_or(0, |g| g.score as i64);
+                match client.side {
+                    TourneySide::Left => (left + score, right),
+                    TourneySide::Right => (left, right + score),
+                }
+            })
+    }
+}
@@ -0,0 +1,57 @@
+use crate::reader::chat::common::ChatMessage;
+
+/// State the manager shares with its clients
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TourneyIpcState {
+    Initialising,
+    Idle,
+    WaitingForClients,
+    Playing,
+    Ranking,
+    #[default]
+    Unknown,
+}
+
+impl From<i32> for TourneyIpcState {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => Self::Initialising,
+            1 => Self::Idle,
+            2 => Self::WaitingForClients,
+            3 => Self::Playing,
+            4 => Self::Ranking,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TourneyTeam {
+    pub name: String,
+    /// sum of the scores of the team clients, as shown by the manager
+    pub score: i32,
+    pub stars: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TourneyManagerInfo {
+    pub ipc_state: TourneyIpcState,
+    pub best_of: i32,
+    pub stars_visible: bool,
+    pub score_visible: bool,
+    pub left: TourneyTeam,
+    pub right: TourneyTeam,
+    pub chat: Vec<ChatMessage>,
+}
+
+impl TourneyManagerInfo {
+    /// Stars needed to win the match
+    pub fn first_to(&self) -> i32 {
+        self.best_of / 2 + 1
+    }
+
+    /// Score difference, positive when the left team leads
+    pub fn score_diff(&self) -> i32 {
+        self.left.score - self.right.score
+    }
+}
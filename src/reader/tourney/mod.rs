@@ -0,0 +1,37 @@
+pub mod clients;
+pub mod common;
+pub mod stable;
+
+use crate::impl_osu_accessor;
+use crate::reader::chat::common::ChatMessage;
+use crate::reader::common::OsuClientKind;
+use crate::reader::structs::State;
+use crate::reader::tourney::common::{TourneyIpcState, TourneyManagerInfo, TourneyTeam};
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Tournament manager state, see [`clients::Tourney`] to also read the clients
+pub struct TourneyReader<'a> {
+    pub process: &'a Process,
+    pub state: &'a mut State,
+    pub osu_type: OsuClientKind,
+}
+
+impl<'a> TourneyReader<'a> {
+    pub fn new(p: &'a Process, state: &'a mut State, osu_type: OsuClientKind) -> Self {
+        Self {
+            process: p,
+            state,
+            osu_type,
+        }
+    }
+    impl_osu_accessor! {
+        fn is_manager() -> bool => stable::memory::is_manager,
+        fn ipc_state() -> TourneyIpcState => stable::memory::ipc_state,
+        fn left_team() -> TourneyTeam => stable::memory::left_team,
+        fn right_team() -> TourneyTeam => stable::memory::right_team,
+        fn best_of() -> i32 => stable::memory::best_of,
+        fn chat() -> Vec<ChatMessage> => stable::memory::chat,
+        fn info() -> TourneyManagerInfo => stable::memory::info,
+    }
+}
@@ -0,0 +1,79 @@
+use crate::reader::chat::common::ChatMessage;
+use crate::reader::chat::stable::memory::read_messages;
+use crate::reader::common::stable::memory::check_game_state;
+use crate::reader::common::GameState;
+use crate::reader::gameplay::stable::offset::GAMEPLAY_OFFSET;
+use crate::reader::structs::State;
+use crate::reader::tourney::common::{TourneyIpcState, TourneyManagerInfo, TourneyTeam};
+use crate::reader::tourney::stable::offset::TOURNEY_OFFSET;
+use crate::Error;
+use rosu_mem::process::{Process, ProcessTraits};
+
+pub fn manager_addr(p: &Process, state: &mut State) -> Result<i32, Error> {
+    if !check_game_state(p, state, GameState::Tourney)? {
+        return Err(Error::NotAvailable("Not a tourney manager".to_string()));
+    }
+    let rulesets_addr = p.read_i32(state.addresses.rulesets - GAMEPLAY_OFFSET.ptr)?;
+    Ok(p.read_i32(rulesets_addr + TOURNEY_OFFSET.screen)?)
+}
+
+pub fn is_manager(p: &Process, state: &mut State) -> Result<bool, Error> {
+    check_game_state(p, state, GameState::Tourney)
+}
+
+pub fn ipc_state(p: &Process, state: &mut State) -> Result<TourneyIpcState, Error> {
+    let manager_addr = manager_addr(p, state)?;
+    Ok(TourneyIpcState::from(
+        p.read_i32(manager_addr + TOURNEY_OFFSET.ipc_state)?,
+    ))
+}
+
+pub fn left_team(p: &Process, state: &mut State) -> Result<TourneyTeam, Error> {
+    let manager_addr = manager_addr(p, state)?;
+    read_team(p, p.read_i32(manager_addr + TOURNEY_OFFSET.left_team)?)
+}
+
+pub fn right_team(p: &Process, state: &mut State) -> Result<TourneyTeam, Error> {
+    let manager_addr = manager_addr(p, state)?;
+    read_team(p, p.read_i32(manager_addr + TOURNEY_OFFSET.right_team)?)
+}
+
+pub fn best_of(p: &Process, state: &mut State) -> Result<i32, Error> {
+    let manager_addr = manager_addr(p, state)?;
+    let right_addr = p.read_i32(manager_addr + TOURNEY_OFFSET.right_team)?;
+    Ok(p.read_i32(right_addr + TOURNEY_OFFSET.team.best_of)?)
+}
+
+pub fn chat(p: &Process, state: &mut State) -> Result<Vec<ChatMessage>, Error> {
+    let manager_addr = manager_addr(p, state)?;
+    read_chat(p, manager_addr)
+}
+
+pub fn info(p: &Process, state: &mut State) -> Result<TourneyManagerInfo, Error> {
+    let manager_addr = manager_addr(p, state)?;
+    let left_addr = p.read_i32(manager_addr + TOURNEY_OFFSET.left_team)?;
+    let right_addr = p.read_i32(manager_addr + TOURNEY_OFFSET.right_team)?;
+    Ok(TourneyManagerInfo {
+        ipc_state: TourneyIpcState::from(p.read_i32(manager_addr + TOURNEY_OFFSET.ipc_state)?),
+        best_of: p.read_i32(right_addr + TOURNEY_OFFSET.team.best_of)?,
+        stars_visible: p.read_u8(right_addr + TOURNEY_OFFSET.team.stars_visible)? != 0,
+        score_visible: p.read_u8(right_addr + TOURNEY_OFFSET.team.score_visible)? != 0,
+        left: read_team(p, left_addr)?,
+        right: read_team(p, right_addr)?,
+        chat: read_chat(p, manager_addr)?,
+    })
+}
+
+fn read_team(p: &Process, team_addr: i32) -> Result<TourneyTeam, Error> {
+    let offset = &TOURNEY_OFFSET.team;
+    let name_base = p.read_i32(team_addr + offset.name_base)?;
+    Ok(TourneyTeam {
+        name: p.read_string(name_base + offset.name)?,
+        score: p.read_i32(team_addr + offset.score)?,
+        stars: p.read_i32(team_addr + offset.stars)?,
+    })
+}
+
+fn read_chat(p: &Process, manager_addr: i32) -> Result<Vec<ChatMessage>, Error> {
+    read_messages(p, p.read_i32(manager_addr + TOURNEY_OFFSET.chat)?)
+}
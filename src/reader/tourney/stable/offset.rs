@@ -0,0 +1,40 @@
+pub struct TourneyOffset {
+    pub screen: i32,
+    pub ipc_state: i32,
+    pub left_team: i32,
+    pub right_team: i32,
+    pub chat: i32,
+    pub team: TourneyTeamOffset,
+}
+
+// The manager screen is the current mode behind the rulesets address like the
+// gameplay ruleset
+pub(crate) const TOURNEY_OFFSET: TourneyOffset = TourneyOffset {
+    screen: 0x4,
+    ipc_state: 0x54,
+    left_team: 0x1C,
+    right_team: 0x20,
+    chat: 0x30, // List<Message> of the match channel, not checked on every build
+    team: TOURNEY_TEAM_OFFSET,
+};
+
+pub struct TourneyTeamOffset {
+    pub name_base: i32,
+    pub name: i32,
+    pub score: i32,
+    pub stars: i32,
+    /// only on the right team
+    pub best_of: i32,
+    pub stars_visible: i32,
+    pub score_visible: i32,
+}
+
+pub(crate) const TOURNEY_TEAM_OFFSET: TourneyTeamOffset = TourneyTeamOffset {
+    name_base: 0x20,
+    name: 0x144,
+    score: 0x28,
+    stars: 0x2C,
+    best_of: 0x30,
+    stars_visible: 0x38,
+    score_visible: 0x39,
+};
@@ -0,0 +1,212 @@
+//! Paths of osu! running under Wine, Proton or umu on Linux
+//!
+//! osu! only knows windows paths (`C:\osu!\Songs`, `Z:\home\...`) or paths
+//! relative to its folder, they are translated with the wine prefix of the
+//! process so they can be opened from Linux
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::reader::structs::State;
+use rosu_mem::process::Process;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WineKind {
+    Wine,
+    /// Steam's Proton, the prefix is in `STEAM_COMPAT_DATA_PATH`
+    Proton,
+    /// umu-launcher, Proton outside of Steam
+    Umu,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WinePrefix {
+    pub kind: WineKind,
+    /// `WINEPREFIX`, the folder holding `drive_c` and `dosdevices`
+    pub path: PathBuf,
+}
+
+impl WinePrefix {
+    pub fn new(kind: WineKind, path: impl Into<PathBuf>) -> Self {
+        Self {
+            kind,
+            path: path.into(),
+        }
+    }
+
+    /// Finds the prefix from the environment variables of a process already
+    /// known to run under wine, wine defaults to `~/.wine` without `WINEPREFIX`
+    ///
+    /// Only `None` when neither the prefix nor `HOME` are set, see [`detect`]
+    /// to know whether osu! runs under wine
+    pub fn from_env(vars: &HashMap<String, String>) -> Option<Self> {
+        let var = |name: &str| vars.get(name).filter(|v| !v.is_empty());
+        let kind = if vars.keys().any(|k| k.starts_with("UMU_")) {
+            WineKind::Umu
+        } else if var("STEAM_COMPAT_DATA_PATH").is_some() {
+            WineKind::Proton
+        } else {
+            WineKind::Wine
+        };
+
+        let path = match (var("WINEPREFIX"), var("STEAM_COMPAT_DATA_PATH")) {
+            (Some(prefix), _) => PathBuf::from(prefix),
+            (None, Some(compat_data)) => Path::new(compat_data).join("pfx"),
+            (None, None) => Path::new(var("HOME")?).join(".wine"),
+        };
+        Some(Self::new(kind, path))
+    }
+
+    /// Linux folder of a drive, from the `dosdevices` links of the prefix
+    ///
+    /// Without the link, `C:` is `drive_c` and `Z:` is `/` like a new prefix
+    pub fn drive(&self, letter: char) -> PathBuf {
+        let letter = letter.to_ascii_lowercase();
+        let link = self.path.join("dosdevices").join(format!("{letter}:"));
+        if let Ok(path) = std::fs::canonicalize(&link) {
+            return path;
+        }
+        match letter {
+            'c' => self.path.join("drive_c"),
+            'z' => PathBuf::from("/"),
+            _ => link,
+        }
+    }
+
+    /// Translates a path given by osu! to a Linux path
+    ///
+    /// Drive paths go through [`WinePrefix::drive`], relative paths are
+    /// joined to `base_dir` (usually the osu! folder) when given
+    pub fn translate(&self, path: &str, base_dir: Option<&Path>) -> PathBuf {
+        let path = path.replace('\\', "/");
+        if let Some((letter, rest)) = split_drive(&path) {
+            return self.drive(letter).join(rest.trim_start_matches('/'));
+        }
+        match base_dir {
+            Some(base_dir) if Path::new(&path).is_relative() => base_dir.join(path),
+            _ => PathBuf::from(path),
+        }
+    }
+}
+
+/// Splits `C:/osu!` into the drive letter and the rest of the path
+fn split_drive(path: &str) -> Option<(char, &str)> {
+    let mut chars = path.chars();
+    let letter = chars.next().filter(char::is_ascii_alphabetic)?;
+    let rest = chars.as_str().strip_prefix(':')?;
+    (rest.is_empty() || rest.starts_with('/')).then_some((letter, rest))
+}
+
+/// Wine prefix of the osu! process, `None` when osu! runs natively
+///
+/// Reads `/proc`, [`executable_dir`] and [`resolve_path`] only detect it once
+/// per process
+#[cfg(target_os = "linux")]
+pub fn detect(p: &Process) -> Option<WinePrefix> {
+    let environ = std::fs::read(format!("/proc/{}/environ", p.pid)).ok()?;
+    let vars: HashMap<String, String> = String::from_utf8_lossy(&environ)
+        .split('\0')
+        .filter_map(|var| var.split_once('='))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    let executable = std::fs::read_link(format!("/proc/{}/exe", p.pid)).ok();
+    is_wine(executable.as_deref(), &vars).then(|| WinePrefix::from_env(&vars))?
+}
+
+/// Wine prefix of the osu! process, `None` when osu! runs natively
+#[cfg(not(target_os = "linux"))]
+pub fn detect(_p: &Process) -> Option<WinePrefix> {
+    None
+}
+
+/// Whether a process runs under wine, from its executable (the wine loader
+/// or preloader) or the variables set by wine, Proton and umu
+pub fn is_wine(executable: Option<&Path>, vars: &HashMap<String, String>) -> bool {
+    let wine_executable = executable
+        .and_then(Path::file_name)
+        .is_some_and(|name| name.to_string_lossy().starts_with("wine"));
+    wine_executable
+        || vars.keys().any(|k| {
+            k.starts_with("WINE") || k.starts_with("UMU_") || k == "STEAM_COMPAT_DATA_PATH"
+        })
+}
+
+/// Wine prefix and osu! folder of the process, kept in [`State`] so `/proc`
+/// is read and the paths are canonicalized once per process
+#[derive(Debug, Clone, Default)]
+pub struct ProcessPaths {
+    detected: Option<DetectedPaths>,
+}
+
+#[derive(Debug, Clone)]
+struct DetectedPaths {
+    pid: u32,
+    prefix: Option<WinePrefix>,
+    executable_dir: Option<PathBuf>,
+    /// last path given to `resolve_path` and its translation
+    last_resolved: Option<(String, PathBuf)>,
+}
+
+impl ProcessPaths {
+    fn get(&mut self, p: &Process) -> &mut DetectedPaths {
+        let pid = p.pid as u32;
+        if self.detected.as_ref().is_some_and(|d| d.pid != pid) {
+            self.detected = None;
+        }
+        self.detected.get_or_insert_with(|| {
+            let prefix = detect(p);
+            let executable_dir = executable_dir_in(p, prefix.as_ref());
+            DetectedPaths {
+                pid,
+                prefix,
+                executable_dir,
+                last_resolved: None,
+            }
+        })
+    }
+}
+
+/// Wine prefix of the osu! process like [`detect`], only detected once
+pub fn prefix(p: &Process, state: &mut State) -> Option<WinePrefix> {
+    state.paths.get(p).prefix.clone()
+}
+
+/// osu! folder, rosu-mem drops the drive letter of the wine command line so
+/// it's only right for `Z:` paths
+pub fn executable_dir(p: &Process, state: &mut State) -> Option<PathBuf> {
+    state.paths.get(p).executable_dir.clone()
+}
+
+fn executable_dir_in(p: &Process, prefix: Option<&WinePrefix>) -> Option<PathBuf> {
+    prefix
+        .and_then(|prefix| {
+            let cmd_line = std::fs::read_to_string(format!("/proc/{}/cmdline", p.pid)).ok()?;
+            let executable = cmd_line.split('\0').next()?;
+            split_drive(&executable.replace('\\', "/"))?;
+            prefix
+                .translate(executable, None)
+                .parent()
+                .map(Path::to_path_buf)
+        })
+        .or_else(|| p.executable_dir.clone())
+}
+
+/// Absolute path of a path read from osu! memory or config, relative paths
+/// are joined to the osu! folder
+pub fn resolve_path(p: &Process, state: &mut State, path: &str) -> PathBuf {
+    let paths = state.paths.get(p);
+    if let Some((last, resolved)) = &paths.last_resolved {
+        if last == path {
+            return resolved.clone();
+        }
+    }
+    let osu_dir = paths.executable_dir.as_deref();
+    let resolved = match &paths.prefix {
+        Some(prefix) => prefix.translate(path, osu_dir),
+        None => match osu_dir {
+            Some(osu_dir) if Path::new(path).is_relative() => osu_dir.join(path),
+            _ => PathBuf::from(path),
+        },
+    };
+    paths.last_resolved = Some((path.to_string(), resolved.clone()));
+    resolved
+}
@@ -0,0 +1,251 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::db::binary::unix_millis_to_ticks;
+use crate::reader::common::stable::memory::game_state;
+use crate::reader::common::{GameMode, GameState};
+use crate::reader::gameplay::common::{GameplayInfo, GameplayReplayFrame};
+use crate::reader::resultscreen::common::ResultScreenInfo;
+use crate::reader::structs::State;
+use crate::replay::{LifeBarPoint, Replay, ReplayFrame};
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// Replay format version written in captured replays
+pub const CAPTURED_REPLAY_VERSION: i32 = 20250107;
+
+/// Minimum time in ms between two life bar points, same as osu!
+const LIFE_BAR_INTERVAL: i32 = 2000;
+
+#[derive(Debug, Clone)]
+pub enum ReplayCaptureEvent {
+    /// Not playing and nothing captured
+    Idle,
+    Capturing {
+        new_frames: usize,
+    },
+    /// The play ended (result screen, fail, quit or retry)
+    Finished(Box<Replay>),
+}
+
+/// What is read from memory on each update while playing
+#[derive(Debug, Clone)]
+pub struct CaptureSnapshot {
+    pub beatmap_md5: String,
+    pub mode: GameMode,
+    /// max combo of the beatmap, only used until a capture knows it
+    pub beatmap_max_combo: Option<u32>,
+    pub info: GameplayInfo,
+    /// frames recorded by osu! so far
+    pub frame_count: usize,
+    /// frames recorded after the first [`ReplayCapture::frame_count`] ones
+    pub new_frames: Vec<GameplayReplayFrame>,
+}
+
+/// Builds replays from memory while playing, including plays osu! doesn't save
+///
+/// Call [`ReplayCapture::update`] regularly (e.g. every 100ms), frames are read
+/// incrementally so only the new ones are read each time
+///
+/// The replay frames offset is not verified on every osu! build, reading the
+/// frames errors with `NotAvailable` when the list doesn't look valid
+#[derive(Debug, Clone, Default)]
+pub struct ReplayCapture {
+    frames: Vec<GameplayReplayFrame>,
+    beatmap_md5: String,
+    mode: GameMode,
+    beatmap_max_combo: Option<u32>,
+    last_info: Option<GameplayInfo>,
+    life_bar: Vec<LifeBarPoint>,
+}
+
+impl ReplayCapture {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn update(&mut self, p: &Process, state: &mut State) -> Result<ReplayCaptureEvent, Error> {
+        let game_state = game_state(p, state)?;
+        let snapshot = if game_state == GameState::Playing {
+            Some(self.read_snapshot(p, state)?)
+        } else {
+            None
+        };
+        let result = if game_state.is_result_screen() && !self.frames.is_empty() {
+            Some(crate::reader::resultscreen::stable::memory::info(p, state)?)
+        } else {
+            None
+        };
+        Ok(self.push(game_state, snapshot, result.as_ref()))
+    }
+
+    fn read_snapshot(&self, p: &Process, state: &mut State) -> Result<CaptureSnapshot, Error> {
+        use crate::reader::beatmap::stable::{file as beatmap_file, memory as beatmap};
+        use crate::reader::gameplay::stable::memory as gameplay;
+
+        // the played mode, the beatmap can be a convert
+        let mode = gameplay::mode(p, state)?;
+        let info = gameplay::info(p, state)?;
+        let beatmap_max_combo = match self.beatmap_max_combo {
+            Some(_) => None,
+            None => beatmap_file::cached_difficulty(p, state, mode, info.mods)
+                .ok()
+                .map(|difficulty| difficulty.max_combo),
+        };
+        Ok(CaptureSnapshot {
+            beatmap_md5: beatmap::md5(p, state)?,
+            mode,
+            beatmap_max_combo,
+            info,
+            frame_count: gameplay::replay_frame_count(p, state)?,
+            new_frames: gameplay::replay_frames(p, state, self.frames.len())?,
+        })
+    }
+
+    /// Feeds a read, `snapshot` is only needed while playing and `result`
+    /// on the result screen, see [`ReplayCapture::update`]
+    pub fn push(
+        &mut self,
+        game_state: GameState,
+        snapshot: Option<CaptureSnapshot>,
+        result: Option<&ResultScreenInfo>,
+    ) -> ReplayCaptureEvent {
+        match (game_state, snapshot) {
+            (GameState::Playing, Some(snapshot)) => self.push_playing(snapshot),
+            (GameState::Playing, None) if self.frames.is_empty() => ReplayCaptureEvent::Idle,
+            (GameState::Playing, None) => ReplayCaptureEvent::Capturing { new_frames: 0 },
+            _ if !self.frames.is_empty() => {
+                let result = result.filter(|_| game_state.is_result_screen());
+                ReplayCaptureEvent::Finished(Box::new(self.finish(result)))
+            }
+            _ => ReplayCaptureEvent::Idle,
+        }
+    }
+
+    fn push_playing(&mut self, snapshot: CaptureSnapshot) -> ReplayCaptureEvent {
+        let info = snapshot.info;
+
+        // a retry creates a new score so the frames restart from zero
+        let retried = self
+            .last_info
+            .as_ref()
+            .is_some_and(|last| last.retries != info.retries);
+        if !self.frames.is_empty() && (retried || snapshot.frame_count < self.frames.len()) {
+            return ReplayCaptureEvent::Finished(Box::new(self.finish(None)));
+        }
+
+        if self.frames.is_empty() {
+            self.beatmap_md5 = snapshot.beatmap_md5;
+            self.mode = snapshot.mode;
+        }
+        if self.beatmap_max_combo.is_none() {
+            self.beatmap_max_combo = snapshot.beatmap_max_combo;
+        }
+
+        let len = snapshot.new_frames.len();
+        self.frames.extend(snapshot.new_frames);
+
+        let sample_life = self
+            .life_bar
+            .last()
+            .is_none_or(|last| info.ig_time - last.time >= LIFE_BAR_INTERVAL);
+        if sample_life && info.ig_time > 0 {
+            self.life_bar.push(LifeBarPoint {
+                time: info.ig_time,
+                // hp goes from 0 to 200 in memory
+                life: (info.hp / 200.0).clamp(0.0, 1.0),
+            });
+        }
+        self.last_info = Some(info);
+
+        ReplayCaptureEvent::Capturing { new_frames: len }
+    }
+
+    /// Builds the replay from what was captured so far and resets the capture
+    ///
+    /// Headers come from the result screen when given, else from the last
+    /// gameplay read (fail, quit or retry)
+    pub fn finish(&mut self, result: Option<&ResultScreenInfo>) -> Replay {
+        let capture = std::mem::take(self);
+        let info = capture.last_info.unwrap_or(GameplayInfo {
+            score: 0,
+            mods: 0,
+            combo: 0,
+            max_combo: 0,
+            hp: 0.0,
+            username: String::new(),
+            ig_time: 0,
+            retries: 0,
+            hits: Default::default(),
+            kind: Default::default(),
+        });
+
+        let mut prev_time = 0;
+        let frames = capture
+            .frames
+            .iter()
+            .map(|f| {
+                let frame = ReplayFrame {
+                    time_delta: (f.time - prev_time) as i64,
+                    x: f.x,
+                    y: f.y,
+                    keys: f.keys,
+                };
+                prev_time = f.time;
+                frame
+            })
+            .collect();
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as i64)
+            .unwrap_or_default();
+
+        let (player, mode, score, max_combo, hits) = match result {
+            Some(r) => (
+                r.username.clone(),
+                r.mode,
+                r.score,
+                r.max_combo,
+                r.hits.clone(),
+            ),
+            None => (
+                info.username,
+                capture.mode,
+                info.score,
+                info.max_combo,
+                info.hits,
+            ),
+        };
+
+        Replay {
+            mode,
+            version: CAPTURED_REPLAY_VERSION,
+            beatmap_md5: capture.beatmap_md5,
+            player,
+            // osu! computes it from the score on submission, unknown from memory
+            replay_md5: String::new(),
+            // false when the max combo of the beatmap is unknown
+            perfect: capture
+                .beatmap_max_combo
+                .is_some_and(|beatmap_max_combo| max_combo as u16 as u32 >= beatmap_max_combo),
+            hits,
+            score,
+            max_combo,
+            mods: info.mods,
+            life_bar: capture.life_bar,
+            timestamp: unix_millis_to_ticks(now),
+            frames,
+            rng_seed: None,
+            online_score_id: 0,
+            additional_mod_info: None,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+pub mod capture;
+pub mod osr;
+
+use crate::reader::common::GameMode;
+use crate::reader::resultscreen::common::ResultScreenInfo;
+use crate::reader::structs::Hit;
+
+/// Frame time delta marking the frame storing the RNG seed
+pub const RNG_SEED_FRAME: i64 = -12345;
+
+/// osu!standard key bits of [`ReplayFrame::keys`], K1/K2 also set M1/M2
+pub const KEY_M1: u32 = 1;
+pub const KEY_M2: u32 = 2;
+pub const KEY_K1: u32 = 4;
+pub const KEY_K2: u32 = 8;
+pub const KEY_SMOKE: u32 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayFrame {
+    /// time in ms since the previous frame
+    pub time_delta: i64,
+    /// cursor x, or pressed keys bits for mania
+    pub x: f32,
+    pub y: f32,
+    /// pressed keys bits, see `KEY_*` for osu!standard
+    pub keys: u32,
+}
+
+/// One point of the life bar graph shown on the result screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LifeBarPoint {
+    /// time in ms
+    pub time: i32,
+    /// between 0 and 1
+    pub life: f64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Replay {
+    pub mode: GameMode,
+    pub version: i32,
+    pub beatmap_md5: String,
+    pub player: String,
+    pub replay_md5: String,
+    pub hits: Hit,
+    pub score: i32,
+    pub max_combo: i16,
+    pub perfect: bool,
+    pub mods: u32,
+    pub life_bar: Vec<LifeBarPoint>,
+    /// .NET ticks, see `db::binary::ticks_to_unix_millis`
+    pub timestamp: i64,
+    pub frames: Vec<ReplayFrame>,
+    /// stored as the last frame by versions 20130319 and later
+    pub rng_seed: Option<i32>,
+    pub online_score_id: i64,
+    /// only set with Target Practice
+    pub additional_mod_info: Option<f64>,
+}
+
+impl Replay {
+    /// Absolute time in ms of each frame
+    pub fn frame_times(&self) -> Vec<i64> {
+        self.frames
+            .iter()
+            .scan(0i64, |time, frame| {
+                *time += frame.time_delta;
+                Some(*time)
+            })
+            .collect()
+    }
+
+    /// Whether this replay is the play shown by a result screen
+    pub fn matches_result(&self, info: &ResultScreenInfo) -> bool {
+        self.player == info.username
+            && self.mode == info.mode
+            && self.score == info.score
+            && self.max_combo == info.max_combo
+            && self.hits == info.hits
+    }
+}
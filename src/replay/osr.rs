@@ -0,0 +1,238 @@
+use std::path::{Path, PathBuf};
+
+use crate::db::binary::{BinaryReader, BinaryWriter};
+use crate::db::scores::{read_online_score_id, MOD_TARGET_PRACTICE, VERSION_LONG_SCORE_ID};
+use crate::reader::common::GameMode;
+use crate::reader::resultscreen::common::ResultScreenInfo;
+use crate::reader::structs::{Hit, State};
+use crate::replay::{LifeBarPoint, Replay, ReplayFrame, RNG_SEED_FRAME};
+use crate::Error;
+use rosu_mem::process::Process;
+
+impl Replay {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = BinaryReader::new(bytes);
+        let mode = GameMode::from(r.read_u8()? as u32);
+        let version = r.read_i32()?;
+        let beatmap_md5 = r.read_string()?;
+        let player = r.read_string()?;
+        let replay_md5 = r.read_string()?;
+        let hits = Hit {
+            _300: r.read_i16()?,
+            _100: r.read_i16()?,
+            _50: r.read_i16()?,
+            _geki: r.read_i16()?,
+            _katu: r.read_i16()?,
+            _miss: r.read_i16()?,
+        };
+        let score = r.read_i32()?;
+        let max_combo = r.read_i16()?;
+        let perfect = r.read_bool()?;
+        let mods = r.read_i32()? as u32;
+        let life_bar = parse_life_bar(&r.read_string()?)?;
+        let timestamp = r.read_i64()?;
+
+        let data_len = r.read_i32()?;
+        let (frames, rng_seed) = if data_len > 0 {
+            let data = decompress(r.read_bytes(data_len as usize)?)?;
+            parse_frames(&data)?
+        } else {
+            (Vec::new(), None)
+        };
+
+        let online_score_id = read_online_score_id(&mut r, version)?;
+        let additional_mod_info = if mods & MOD_TARGET_PRACTICE != 0 {
+            Some(r.read_f64()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            mode,
+            version,
+            beatmap_md5,
+            player,
+            replay_md5,
+            hits,
+            score,
+            max_combo,
+            perfect,
+            mods,
+            life_bar,
+            timestamp,
+            frames,
+            rng_seed,
+            online_score_id,
+            additional_mod_info,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        let mut w = BinaryWriter::new();
+        w.write_u8(match self.mode {
+            GameMode::Taiko => 1,
+            GameMode::Catch => 2,
+            GameMode::Mania => 3,
+            _ => 0,
+        });
+        w.write_i32(self.version);
+        w.write_string(&self.beatmap_md5);
+        w.write_string(&self.player);
+        w.write_string(&self.replay_md5);
+        w.write_i16(self.hits._300);
+        w.write_i16(self.hits._100);
+        w.write_i16(self.hits._50);
+        w.write_i16(self.hits._geki);
+        w.write_i16(self.hits._katu);
+        w.write_i16(self.hits._miss);
+        w.write_i32(self.score);
+        w.write_i16(self.max_combo);
+        w.write_bool(self.perfect);
+        w.write_i32(self.mods as i32);
+        w.write_string(&format_life_bar(&self.life_bar));
+        w.write_i64(self.timestamp);
+
+        let data = compress(format_frames(&self.frames, self.rng_seed).as_bytes())?;
+        w.write_i32(data.len() as i32);
+        w.write_bytes(&data);
+
+        if self.version >= VERSION_LONG_SCORE_ID {
+            w.write_i64(self.online_score_id);
+        } else {
+            w.write_i32(self.online_score_id as i32);
+        }
+        if self.mods & MOD_TARGET_PRACTICE != 0 {
+            w.write_f64(self.additional_mod_info.unwrap_or_default());
+        }
+        Ok(w.into_inner())
+    }
+
+    pub fn write_to_path(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        Ok(std::fs::write(path, self.to_bytes()?)?)
+    }
+}
+
+fn decompress(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::new();
+    lzma_rs::lzma_decompress(&mut &data[..], &mut out)
+        .map_err(|e| Error::Parse(format!("Invalid replay data: {e}")))?;
+    Ok(out)
+}
+
+fn compress(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::new();
+    let options = lzma_rs::compress::Options {
+        unpacked_size: lzma_rs::compress::UnpackedSize::WriteToHeader(Some(data.len() as u64)),
+    };
+    lzma_rs::lzma_compress_with_options(&mut &data[..], &mut out, &options)?;
+    Ok(out)
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, what: &str) -> Result<T, Error> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| Error::Parse(format!("Invalid {what}: {value:?}")))
+}
+
+fn parse_life_bar(value: &str) -> Result<Vec<LifeBarPoint>, Error> {
+    value
+        .split(',')
+        .filter(|point| !point.trim().is_empty())
+        .map(|point| {
+            let (time, life) = point
+                .split_once('|')
+                .ok_or_else(|| Error::Parse(format!("Invalid life bar point: {point:?}")))?;
+            Ok(LifeBarPoint {
+                time: parse_number(time, "life bar time")?,
+                life: parse_number(life, "life bar value")?,
+            })
+        })
+        .collect()
+}
+
+fn format_life_bar(points: &[LifeBarPoint]) -> String {
+    points
+        .iter()
+        .map(|p| format!("{}|{},", p.time, p.life))
+        .collect()
+}
+
+fn parse_frames(data: &[u8]) -> Result<(Vec<ReplayFrame>, Option<i32>), Error> {
+    let data = std::str::from_utf8(data)
+        .map_err(|e| Error::Parse(format!("Invalid replay frames: {e}")))?;
+    let mut frames = Vec::new();
+    let mut rng_seed = None;
+    for frame in data.split(',').filter(|f| !f.trim().is_empty()) {
+        let mut values = frame.split('|');
+        let mut next = |what| {
+            values
+                .next()
+                .ok_or_else(|| Error::Parse(format!("Missing {what} in frame {frame:?}")))
+        };
+        let time_delta: i64 = parse_number(next("time")?, "frame time")?;
+        let x: f32 = parse_number(next("x")?, "frame x")?;
+        let y: f32 = parse_number(next("y")?, "frame y")?;
+        let keys: u32 = parse_number(next("keys")?, "frame keys")?;
+
+        if time_delta == RNG_SEED_FRAME {
+            rng_seed = Some(keys as i32);
+            continue;
+        }
+        frames.push(ReplayFrame {
+            time_delta,
+            x,
+            y,
+            keys,
+        });
+    }
+    Ok((frames, rng_seed))
+}
+
+fn format_frames(frames: &[ReplayFrame], rng_seed: Option<i32>) -> String {
+    let mut data: String = frames
+        .iter()
+        .map(|f| format!("{}|{}|{}|{},", f.time_delta, f.x, f.y, f.keys))
+        .collect();
+    if let Some(seed) = rng_seed {
+        data.push_str(&format!("{RNG_SEED_FRAME}|0|0|{seed},"));
+    }
+    data
+}
+
+/// Folder where osu! saves the replays of local scores (`Data/r`)
+pub fn replays_dir(p: &Process, state: &mut State) -> Result<PathBuf, Error> {
+    Ok(crate::db::osu_dir(p, state)?.join("Data").join("r"))
+}
+
+/// Finds the saved replay of the play shown by the result screen
+///
+/// Replays in `Data/r` are named `<beatmap md5>-<timestamp>.osr`, only the
+/// ones of the current beatmap are parsed
+pub fn find_result_replay(
+    p: &Process,
+    state: &mut State,
+    info: &ResultScreenInfo,
+) -> Result<Option<(PathBuf, Replay)>, Error> {
+    let md5 = crate::reader::beatmap::stable::memory::md5(p, state)?;
+    for entry in std::fs::read_dir(replays_dir(p, state)?)? {
+        let path = entry?.path();
+        let is_candidate = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(&md5) && name.ends_with(".osr"));
+        if !is_candidate {
+            continue;
+        }
+        if let Ok(replay) = Replay::from_path(&path) {
+            if replay.matches_result(info) {
+                return Ok(Some((path, replay)));
+            }
+        }
+    }
+    Ok(None)
+}
@@ -0,0 +1,306 @@
+use crate::reader::common::{GameMode, Grade};
+use crate::reader::structs::Hit;
+use crate::Error;
+
+/// Mods making SS and S silver (HD, FL, FadeIn)
+const SILVER_GRADE_MODS: u32 = (1 << 3) | (1 << 10) | (1 << 20);
+
+/// Hit counts of a play named after the judgements of its mode
+///
+/// osu! stores the counts as unsigned 16 bits so they are read back as such,
+/// sums are done in `u32` so long maps don't overflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Judgements {
+    Osu {
+        n300: u32,
+        n100: u32,
+        n50: u32,
+        misses: u32,
+    },
+    Taiko {
+        great: u32,
+        ok: u32,
+        misses: u32,
+    },
+    Catch {
+        fruits: u32,
+        droplets: u32,
+        tiny_droplets: u32,
+        tiny_droplet_misses: u32,
+        /// missed fruits and droplets
+        misses: u32,
+    },
+    Mania {
+        /// MAX/rainbow 300
+        max: u32,
+        n300: u32,
+        n200: u32,
+        n100: u32,
+        n50: u32,
+        misses: u32,
+    },
+}
+
+impl Judgements {
+    /// Maps the generic hit counts to the judgements of the mode
+    ///
+    /// Outside of mania geki/katu are subsets of 300/100 (combo ends), in
+    /// catch `_50` are caught tiny droplets and `_katu` missed ones
+    pub fn from_hits(mode: &GameMode, hit: &Hit) -> Result<Self, Error> {
+        let count = |value: i16| value as u16 as u32;
+        Ok(match mode {
+            GameMode::Osu => Self::Osu {
+                n300: count(hit._300),
+                n100: count(hit._100),
+                n50: count(hit._50),
+                misses: count(hit._miss),
+            },
+            GameMode::Taiko => Self::Taiko {
+                great: count(hit._300),
+                ok: count(hit._100),
+                misses: count(hit._miss),
+            },
+            GameMode::Catch => Self::Catch {
+                fruits: count(hit._300),
+                droplets: count(hit._100),
+                tiny_droplets: count(hit._50),
+                tiny_droplet_misses: count(hit._katu),
+                misses: count(hit._miss),
+            },
+            GameMode::Mania => Self::Mania {
+                max: count(hit._geki),
+                n300: count(hit._300),
+                n200: count(hit._katu),
+                n100: count(hit._100),
+                n50: count(hit._50),
+                misses: count(hit._miss),
+            },
+            GameMode::Unknown => {
+                return Err(Error::Unsupported("Unknown game mode".to_string()));
+            }
+        })
+    }
+
+    pub fn mode(&self) -> GameMode {
+        match self {
+            Self::Osu { .. } => GameMode::Osu,
+            Self::Taiko { .. } => GameMode::Taiko,
+            Self::Catch { .. } => GameMode::Catch,
+            Self::Mania { .. } => GameMode::Mania,
+        }
+    }
+
+    /// Every judgement counted by the accuracy, including tiny droplets
+    pub fn total(&self) -> u32 {
+        match *self {
+            Self::Osu {
+                n300,
+                n100,
+                n50,
+                misses,
+            } => n300 + n100 + n50 + misses,
+            Self::Taiko { great, ok, misses } => great + ok + misses,
+            Self::Catch {
+                fruits,
+                droplets,
+                tiny_droplets,
+                tiny_droplet_misses,
+                misses,
+            } => fruits + droplets + tiny_droplets + tiny_droplet_misses + misses,
+            Self::Mania {
+                max,
+                n300,
+                n200,
+                n100,
+                n50,
+                misses,
+            } => max + n300 + n200 + n100 + n50 + misses,
+        }
+    }
+
+    /// Hit objects judged so far, tiny droplets are not hit objects
+    pub fn judged_objects(&self) -> u32 {
+        match *self {
+            Self::Catch {
+                fruits,
+                droplets,
+                misses,
+                ..
+            } => fruits + droplets + misses,
+            _ => self.total(),
+        }
+    }
+
+    /// Accuracy in percent, 100 when nothing was judged yet like osu! shows
+    pub fn accuracy(&self) -> f64 {
+        let total = self.total();
+        if total == 0 {
+            return 100.0;
+        }
+        let total = total as f64;
+        let accuracy = match *self {
+            Self::Osu {
+                n300, n100, n50, ..
+            } => (n300 as f64 * 300.0 + n100 as f64 * 100.0 + n50 as f64 * 50.0) / (total * 300.0),
+            Self::Taiko { great, ok, .. } => (great as f64 + ok as f64 * 0.5) / total,
+            Self::Catch {
+                fruits,
+                droplets,
+                tiny_droplets,
+                ..
+            } => (fruits + droplets + tiny_droplets) as f64 / total,
+            Self::Mania {
+                max,
+                n300,
+                n200,
+                n100,
+                n50,
+                ..
+            } => {
+                // MAX only matters for score v2, it counts as a 300 here
+                ((max + n300) as f64 * 300.0
+                    + n200 as f64 * 200.0
+                    + n100 as f64 * 100.0
+                    + n50 as f64 * 50.0)
+                    / (total * 300.0)
+            }
+        };
+        accuracy * 100.0
+    }
+
+    /// Grade following osu!stable rules, an empty play is SS
+    pub fn grade(&self, mods: u32) -> Grade {
+        let silver = mods & SILVER_GRADE_MODS != 0;
+        let (ss, s) = if silver {
+            (Grade::XH, Grade::SH)
+        } else {
+            (Grade::X, Grade::S)
+        };
+        let total = self.total();
+        if total == 0 {
+            return ss;
+        }
+
+        let (n300, n50, misses) = match *self {
+            Self::Osu {
+                n300, n50, misses, ..
+            } => (n300, n50, misses),
+            Self::Taiko { great, misses, .. } => (great, 0, misses),
+            // catch and mania grades only depend on the accuracy
+            Self::Catch { .. } => {
+                return accuracy_grade(self.accuracy(), [98.0, 94.0, 90.0, 85.0], ss, s)
+            }
+            Self::Mania { .. } => {
+                return accuracy_grade(self.accuracy(), [95.0, 90.0, 80.0, 70.0], ss, s)
+            }
+        };
+
+        let ratio_300 = n300 as f64 / total as f64;
+        let ratio_50 = n50 as f64 / total as f64;
+        let no_miss = misses == 0;
+        if n300 == total {
+            ss
+        } else if ratio_300 > 0.9 && ratio_50 < 0.01 && no_miss {
+            s
+        } else if (ratio_300 > 0.8 && no_miss) || ratio_300 > 0.9 {
+            Grade::A
+        } else if (ratio_300 > 0.7 && no_miss) || ratio_300 > 0.8 {
+            Grade::B
+        } else if ratio_300 > 0.6 {
+            Grade::C
+        } else {
+            Grade::D
+        }
+    }
+}
+
+/// Grade from the accuracy with the S/A/B/C thresholds, all exclusive
+fn accuracy_grade(accuracy: f64, thresholds: [f64; 4], ss: Grade, s: Grade) -> Grade {
+    if accuracy == 100.0 {
+        ss
+    } else if accuracy > thresholds[0] {
+        s
+    } else if accuracy > thresholds[1] {
+        Grade::A
+    } else if accuracy > thresholds[2] {
+        Grade::B
+    } else if accuracy > thresholds[3] {
+        Grade::C
+    } else {
+        Grade::D
+    }
+}
+
+/// Accuracy in percent of the hit counts, see [`Judgements::accuracy`]
+pub fn calculate_accuracy(gamemode: &GameMode, hit: &Hit) -> Result<f64, Error> {
+    Ok(Judgements::from_hits(gamemode, hit)?.accuracy())
+}
+
+/// Grade of the hit counts, see [`Judgements::grade`]
+pub fn calculate_grade(gamemode: &GameMode, hit: &Hit, mods: u32) -> Result<Grade, Error> {
+    Ok(Judgements::from_hits(gamemode, hit)?.grade(mods))
+}
+
+/// Score V2 multiplier of the mods, stable values
+fn score_v2_multiplier(mods: u32) -> f64 {
+    const MULTIPLIERS: [(u32, f64); 7] = [
+        (1 << 1, 0.5),   // EZ
+        (1 << 3, 1.06),  // HD
+        (1 << 4, 1.1),   // HR
+        (1 << 6, 1.2),   // DT
+        (1 << 8, 0.3),   // HT
+        (1 << 10, 1.12), // FL
+        (1 << 12, 0.9),  // SO
+    ];
+    MULTIPLIERS
+        .iter()
+        .filter(|(bit, _)| mods & bit != 0)
+        .map(|(_, multiplier)| multiplier)
+        .product()
+}
+
+/// Score V2 the play would end with if the rest of the beatmap is played with
+/// the same accuracy and combo ratio
+///
+/// The combo portion uses the max combo over the combo reachable so far
+/// (`beatmap_max_combo` scaled by the judged objects), so it's an estimate
+/// until the end of the beatmap, spinner and bonus objects are not included
+///
+/// Each mode weights combo and accuracy differently, the weights are the ones
+/// of lazer's standardised score:
+/// - osu!: 700k combo, 300k accuracy^10
+/// - taiko: 250k combo, 750k accuracy^3.6
+/// - catch: 600k combo, 400k accuracy^5
+/// - mania: 150k combo, 850k accuracy^(2 + 2 * accuracy)
+pub fn calculate_projected_score(
+    gamemode: &GameMode,
+    hit: &Hit,
+    max_combo: i16,
+    beatmap_max_combo: u32,
+    total_objects: usize,
+    mods: u32,
+) -> Result<f64, Error> {
+    let judgements = Judgements::from_hits(gamemode, hit)?;
+    let judged = judgements.judged_objects();
+    if judged == 0 || total_objects == 0 || beatmap_max_combo == 0 {
+        return Ok(0.0);
+    }
+
+    let accuracy = judgements.accuracy() / 100.0;
+    let progress = (judged as f64 / total_objects as f64).min(1.0);
+    let reachable_combo = (beatmap_max_combo as f64 * progress).max(1.0);
+    let combo_ratio = (max_combo as u16 as f64 / reachable_combo).min(1.0);
+
+    let (combo_weight, accuracy_portion) = match judgements {
+        Judgements::Osu { .. } => (700_000.0, accuracy.powi(10)),
+        Judgements::Taiko { .. } => (250_000.0, accuracy.powf(3.6)),
+        Judgements::Catch { .. } => (600_000.0, accuracy.powi(5)),
+        Judgements::Mania { .. } => (150_000.0, accuracy.powf(2.0 + 2.0 * accuracy)),
+    };
+    let accuracy_weight = 1_000_000.0 - combo_weight;
+
+    Ok(
+        (combo_weight * combo_ratio + accuracy_weight * accuracy_portion)
+            * score_v2_multiplier(mods),
+    )
+}
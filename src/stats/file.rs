@@ -0,0 +1,166 @@
+use std::path::Path;
+use std::time::Duration;
+
+use crate::db::binary::{BinaryReader, BinaryWriter};
+use crate::reader::common::GameMode;
+use crate::reader::structs::Hit;
+use crate::stats::{now_millis, PlayOutcome, SessionPlay, SessionStats, UserSnapshot};
+use crate::Error;
+
+/// Version of the session file format
+pub const SESSION_FILE_VERSION: i32 = 1;
+
+/// Sessions untouched for longer than this are not resumed by default
+pub const DEFAULT_SESSION_TIMEOUT: Duration = Duration::from_secs(6 * 60 * 60);
+
+impl SessionStats {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let mut r = BinaryReader::new(bytes);
+        let version = r.read_i32()?;
+        if version != SESSION_FILE_VERSION {
+            return Err(Error::Parse(format!(
+                "Unsupported session file version {version}"
+            )));
+        }
+        let started_at = r.read_i64()?;
+        let updated_at = r.read_i64()?;
+        let start_user = read_user(&mut r)?;
+        let current_user = read_user(&mut r)?;
+
+        // size of a play with an empty md5
+        let count = r.read_count(41)?;
+        let mut plays = Vec::with_capacity(count);
+        for _ in 0..count {
+            plays.push(read_play(&mut r)?);
+        }
+
+        Ok(Self {
+            started_at,
+            updated_at,
+            start_user,
+            current_user,
+            plays,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut w = BinaryWriter::new();
+        w.write_i32(SESSION_FILE_VERSION);
+        w.write_i64(self.started_at);
+        w.write_i64(self.updated_at);
+        write_user(&mut w, self.start_user.as_ref());
+        write_user(&mut w, self.current_user.as_ref());
+        w.write_i32(self.plays.len() as i32);
+        for play in &self.plays {
+            write_play(&mut w, play);
+        }
+        w.into_inner()
+    }
+
+    /// Writes to a temporary file first so a crash never leaves a broken session
+    pub fn write_to_path(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let path = path.as_ref();
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, self.to_bytes())?;
+        Ok(std::fs::rename(tmp, path)?)
+    }
+
+    /// Resumes the session saved at `path` if it was updated less than
+    /// `timeout` ago, else starts a new one
+    ///
+    /// A missing or unreadable file also starts a new session
+    pub fn load_or_new(path: impl AsRef<Path>, timeout: Duration) -> Self {
+        match Self::from_path(path) {
+            Ok(session) if now_millis() - session.updated_at <= timeout.as_millis() as i64 => {
+                session
+            }
+            _ => Self::new(),
+        }
+    }
+}
+
+fn read_user(r: &mut BinaryReader) -> Result<Option<UserSnapshot>, Error> {
+    if !r.read_bool()? {
+        return Ok(None);
+    }
+    Ok(Some(UserSnapshot {
+        pp: r.read_i32()?,
+        rank: r.read_i32()?,
+        ranked_score: r.read_i64()?,
+        accuracy: r.read_f64()?,
+        playcount: r.read_i32()?,
+    }))
+}
+
+fn write_user(w: &mut BinaryWriter, user: Option<&UserSnapshot>) {
+    w.write_bool(user.is_some());
+    if let Some(user) = user {
+        w.write_i32(user.pp);
+        w.write_i32(user.rank);
+        w.write_i64(user.ranked_score);
+        w.write_f64(user.accuracy);
+        w.write_i32(user.playcount);
+    }
+}
+
+fn read_play(r: &mut BinaryReader) -> Result<SessionPlay, Error> {
+    let beatmap_md5 = r.read_string()?;
+    let mode = GameMode::from(r.read_u8()? as u32);
+    let outcome = match r.read_u8()? {
+        0 => PlayOutcome::Passed,
+        1 => PlayOutcome::Failed,
+        2 => PlayOutcome::Retried,
+        3 => PlayOutcome::Quit,
+        value => return Err(Error::Parse(format!("Invalid play outcome {value}"))),
+    };
+    Ok(SessionPlay {
+        beatmap_md5,
+        mode,
+        outcome,
+        score: r.read_i32()?,
+        mods: r.read_u32()?,
+        max_combo: r.read_i16()?,
+        hits: Hit {
+            _geki: r.read_i16()?,
+            _300: r.read_i16()?,
+            _katu: r.read_i16()?,
+            _100: r.read_i16()?,
+            _50: r.read_i16()?,
+            _miss: r.read_i16()?,
+        },
+        play_time: Duration::from_millis(r.read_i64()?.max(0) as u64),
+        ended_at: r.read_i64()?,
+    })
+}
+
+fn write_play(w: &mut BinaryWriter, play: &SessionPlay) {
+    w.write_string(&play.beatmap_md5);
+    w.write_u8(match play.mode {
+        GameMode::Osu => 0,
+        GameMode::Taiko => 1,
+        GameMode::Catch => 2,
+        GameMode::Mania => 3,
+        GameMode::Unknown => 255,
+    });
+    w.write_u8(match play.outcome {
+        PlayOutcome::Passed => 0,
+        PlayOutcome::Failed => 1,
+        PlayOutcome::Retried => 2,
+        PlayOutcome::Quit => 3,
+    });
+    w.write_i32(play.score);
+    w.write_u32(play.mods);
+    w.write_i16(play.max_combo);
+    w.write_i16(play.hits._geki);
+    w.write_i16(play.hits._300);
+    w.write_i16(play.hits._katu);
+    w.write_i16(play.hits._100);
+    w.write_i16(play.hits._50);
+    w.write_i16(play.hits._miss);
+    w.write_i64(play.play_time.as_millis() as i64);
+    w.write_i64(play.ended_at);
+}
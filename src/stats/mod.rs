@@ -0,0 +1,230 @@
+pub mod file;
+
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::reader::common::GameMode;
+use crate::reader::gameplay::session::{PlayEvent, PlayResult};
+use crate::reader::structs::{Hit, State};
+use crate::reader::user::common::UserInfo;
+use crate::scoring::calculate_accuracy;
+use crate::Error;
+use rosu_mem::process::Process;
+
+/// How an attempt ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayOutcome {
+    Passed,
+    Failed,
+    Retried,
+    Quit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionPlay {
+    pub beatmap_md5: String,
+    pub mode: GameMode,
+    pub outcome: PlayOutcome,
+    pub score: i32,
+    pub mods: u32,
+    pub max_combo: i16,
+    pub hits: Hit,
+    pub play_time: Duration,
+    /// unix time in ms
+    pub ended_at: i64,
+}
+
+impl SessionPlay {
+    pub fn accuracy(&self) -> Result<f64, Error> {
+        calculate_accuracy(&self.mode, &self.hits)
+    }
+}
+
+/// Profile values compared between the start of the session and now
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UserSnapshot {
+    pub pp: i32,
+    pub rank: i32,
+    pub ranked_score: i64,
+    pub accuracy: f64,
+    pub playcount: i32,
+}
+
+impl From<&UserInfo> for UserSnapshot {
+    fn from(info: &UserInfo) -> Self {
+        Self {
+            pp: info.pp,
+            rank: info.rank,
+            ranked_score: info.rankedscore,
+            accuracy: info.accuracy,
+            playcount: info.playcount,
+        }
+    }
+}
+
+/// Attempt counts of a beatmap during the session
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BeatmapSessionStats {
+    pub plays: u32,
+    pub passes: u32,
+    pub fails: u32,
+    pub retries: u32,
+    pub quits: u32,
+}
+
+/// Statistics of a play session, fed with [`PlayEvent`]s and user profile
+/// snapshots, see [`file`] to keep it across osu! restarts
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionStats {
+    /// unix time in ms
+    pub started_at: i64,
+    /// unix time in ms of the last recorded play or user snapshot
+    pub updated_at: i64,
+    pub start_user: Option<UserSnapshot>,
+    pub current_user: Option<UserSnapshot>,
+    pub plays: Vec<SessionPlay>,
+}
+
+impl Default for SessionStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+impl SessionStats {
+    pub fn new() -> Self {
+        let now = now_millis();
+        Self {
+            started_at: now,
+            updated_at: now,
+            start_user: None,
+            current_user: None,
+            plays: Vec::new(),
+        }
+    }
+
+    /// Records the end of an attempt, other events and plays that are not the
+    /// user's own (replays, spectating, autoplay) are ignored
+    pub fn record(&mut self, event: &PlayEvent) {
+        let (outcome, result) = match event {
+            PlayEvent::Completed(result) => (PlayOutcome::Passed, result),
+            PlayEvent::Failed(result) => (PlayOutcome::Failed, result),
+            PlayEvent::Retried(result) => (PlayOutcome::Retried, result),
+            PlayEvent::Quit(result) => (PlayOutcome::Quit, result),
+            _ => return,
+        };
+        if result.info.kind.is_own_play() {
+            self.record_result(outcome, result);
+        }
+    }
+
+    pub fn record_result(&mut self, outcome: PlayOutcome, result: &PlayResult) {
+        self.updated_at = now_millis();
+        self.plays.push(SessionPlay {
+            beatmap_md5: result.beatmap_md5.clone(),
+            mode: result.mode,
+            outcome,
+            score: result.info.score,
+            mods: result.info.mods,
+            max_combo: result.info.max_combo,
+            hits: result.info.hits.clone(),
+            play_time: result.play_time,
+            ended_at: self.updated_at,
+        });
+    }
+
+    /// Records a profile snapshot, the first one is the session baseline
+    pub fn record_user(&mut self, info: &UserInfo) {
+        let snapshot = UserSnapshot::from(info);
+        self.updated_at = now_millis();
+        self.start_user.get_or_insert(snapshot);
+        self.current_user = Some(snapshot);
+    }
+
+    /// Reads the user profile of the running osu! instance and records it
+    pub fn update_user(&mut self, p: &Process, state: &mut State) -> Result<(), Error> {
+        let info = crate::reader::user::stable::memory::info(p, state)?;
+        self.record_user(&info);
+        Ok(())
+    }
+
+    fn count(&self, outcome: PlayOutcome) -> u32 {
+        self.plays.iter().filter(|p| p.outcome == outcome).count() as u32
+    }
+
+    pub fn passes(&self) -> u32 {
+        self.count(PlayOutcome::Passed)
+    }
+
+    pub fn fails(&self) -> u32 {
+        self.count(PlayOutcome::Failed)
+    }
+
+    pub fn retries(&self) -> u32 {
+        self.count(PlayOutcome::Retried)
+    }
+
+    pub fn quits(&self) -> u32 {
+        self.count(PlayOutcome::Quit)
+    }
+
+    /// Time spent playing, pauses excluded
+    pub fn time_played(&self) -> Duration {
+        self.plays.iter().map(|p| p.play_time).sum()
+    }
+
+    fn user_delta<T>(&self, value: impl Fn(&UserSnapshot) -> T) -> Option<(T, T)> {
+        Some((
+            value(self.start_user.as_ref()?),
+            value(self.current_user.as_ref()?),
+        ))
+    }
+
+    pub fn pp_gain(&self) -> Option<i32> {
+        self.user_delta(|u| u.pp).map(|(start, now)| now - start)
+    }
+
+    /// Ranks climbed since the start, positive when the rank went up
+    pub fn rank_gain(&self) -> Option<i32> {
+        self.user_delta(|u| u.rank).map(|(start, now)| start - now)
+    }
+
+    pub fn ranked_score_gain(&self) -> Option<i64> {
+        self.user_delta(|u| u.ranked_score)
+            .map(|(start, now)| now - start)
+    }
+
+    pub fn beatmap(&self, md5: &str) -> BeatmapSessionStats {
+        self.plays.iter().filter(|p| p.beatmap_md5 == md5).fold(
+            BeatmapSessionStats::default(),
+            |mut stats, play| {
+                stats.plays += 1;
+                match play.outcome {
+                    PlayOutcome::Passed => stats.passes += 1,
+                    PlayOutcome::Failed => stats.fails += 1,
+                    PlayOutcome::Retried => stats.retries += 1,
+                    PlayOutcome::Quit => stats.quits += 1,
+                }
+                stats
+            },
+        )
+    }
+
+    /// Attempt counts of every beatmap played during the session
+    pub fn beatmaps(&self) -> HashMap<&str, BeatmapSessionStats> {
+        let mut beatmaps = HashMap::new();
+        for play in &self.plays {
+            beatmaps
+                .entry(play.beatmap_md5.as_str())
+                .or_insert_with(|| self.beatmap(&play.beatmap_md5));
+        }
+        beatmaps
+    }
+}
@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use rosu_memory_lib::reader::beatmap::cache::BeatmapCache;
+use rosu_memory_lib::reader::common::GameMode;
+use rosu_memory_lib::reader::structs::Hit;
+use rosu_memory_lib::scoring::calculate_projected_score;
+
+const CATCH: &str = "tests/fixtures/catch.osu";
+
+#[test]
+fn catch_judged_objects() {
+    let mut cache = BeatmapCache::default();
+    let difficulty = cache
+        .difficulty(Path::new(CATCH), "catch", GameMode::Catch, 0)
+        .unwrap();
+    // 3 fruits, a slider with 2 fruits, a droplet and tiny droplets
+    assert_eq!(
+        cache
+            .pp_map(Path::new(CATCH), "catch")
+            .unwrap()
+            .hit_objects
+            .len(),
+        4
+    );
+    assert_eq!(difficulty.judged_objects(), 6);
+    assert_eq!(difficulty.max_combo, 6);
+
+    // every fruit and droplet caught, the tiny droplets are not objects
+    let hits = Hit {
+        _300: 5,
+        _100: 1,
+        _50: 14,
+        ..Default::default()
+    };
+    let score = calculate_projected_score(
+        &GameMode::Catch,
+        &hits,
+        6,
+        difficulty.max_combo,
+        difficulty.judged_objects() as usize,
+        0,
+    )
+    .unwrap();
+    assert_eq!(score, 1_000_000.0);
+}
+
+#[test]
+fn difficulty_by_mods() {
+    let mut cache = BeatmapCache::default();
+    let path = Path::new(CATCH);
+    let nomod = cache.difficulty(path, "catch", GameMode::Catch, 0).unwrap();
+    let dt = cache
+        .difficulty(path, "catch", GameMode::Catch, 64)
+        .unwrap();
+    assert!(dt.stars > nomod.stars);
+    let again = cache.difficulty(path, "catch", GameMode::Catch, 0).unwrap();
+    assert_eq!(again.stars, nomod.stars);
+    // both are kept with the beatmap
+    assert_eq!(cache.len(), 1);
+
+    // a catch beatmap can't be played in another mode
+    assert!(cache.difficulty(path, "catch", GameMode::Osu, 0).is_err());
+}
+
+/// Copies the catch fixture to `name` in a temp folder of its own
+fn copy_fixture(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("beatmap-cache-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::copy(CATCH, &path).unwrap();
+    path
+}
+
+/// Changes the difficulty name without changing the modification time, only
+/// a new parse can see it
+fn rename_difficulty(path: &Path, version: &str) {
+    let modified = std::fs::metadata(path).unwrap().modified().unwrap();
+    let content = std::fs::read_to_string(path)
+        .unwrap()
+        .replace("Version:Salad", &format!("Version:{version}"));
+    std::fs::write(path, content).unwrap();
+    let file = std::fs::File::options().write(true).open(path).unwrap();
+    file.set_modified(modified).unwrap();
+}
+
+fn version(cache: &mut BeatmapCache, path: &Path, md5: &str) -> String {
+    cache.metadata(path, md5).unwrap().version.clone()
+}
+
+#[test]
+fn least_recently_used_is_evicted() {
+    let a = copy_fixture("lru-a.osu");
+    let b = copy_fixture("lru-b.osu");
+    let c = copy_fixture("lru-c.osu");
+    let mut cache = BeatmapCache::new(2);
+    version(&mut cache, &a, "a");
+    version(&mut cache, &b, "b");
+    // `a` is used again so `b` is the one evicted by `c`
+    version(&mut cache, &a, "a");
+    version(&mut cache, &c, "c");
+    assert_eq!(cache.len(), 2);
+
+    rename_difficulty(&a, "Changed");
+    rename_difficulty(&b, "Changed");
+    assert_eq!(version(&mut cache, &a, "a"), "Salad");
+    assert_eq!(version(&mut cache, &b, "b"), "Changed");
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn new_md5_is_parsed_again() {
+    let path = copy_fixture("md5.osu");
+    let mut cache = BeatmapCache::default();
+    assert_eq!(version(&mut cache, &path, "old"), "Salad");
+
+    rename_difficulty(&path, "Changed");
+    assert_eq!(version(&mut cache, &path, "old"), "Salad");
+    assert_eq!(version(&mut cache, &path, "new"), "Changed");
+    // the same path is replaced, not added
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn new_modification_time_is_parsed_again() {
+    let path = copy_fixture("mtime.osu");
+    let mut cache = BeatmapCache::default();
+    assert_eq!(version(&mut cache, &path, "md5"), "Salad");
+
+    // saved in the editor, memory still has the old md5
+    rename_difficulty(&path, "Changed");
+    let file = std::fs::File::options().write(true).open(&path).unwrap();
+    file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(60))
+        .unwrap();
+    assert_eq!(version(&mut cache, &path, "md5"), "Changed");
+    assert_eq!(cache.len(), 1);
+}
@@ -0,0 +1,120 @@
+use rosu_memory_lib::reader::beatmap::common::BeatmapDifficultySettings;
+use rosu_memory_lib::reader::beatmap::stable::file::{
+    difficulty_attributes_for_mode, difficulty_attributes_from_beatmap, performance_from_beatmap,
+};
+use rosu_memory_lib::reader::common::GameMode;
+
+const MAP: &str = "osu file format v14
+
+[General]
+Mode: 0
+
+[Difficulty]
+HPDrainRate:5
+CircleSize:4
+OverallDifficulty:8
+ApproachRate:9
+SliderMultiplier:1.4
+SliderTickRate:1
+
+[TimingPoints]
+1000,300,4,2,0,50,1,0
+
+[HitObjects]
+64,96,1000,1,0,0:0:0:0:
+112,136,1300,1,0,0:0:0:0:
+160,176,1600,1,0,0:0:0:0:
+208,216,1900,1,0,0:0:0:0:
+256,256,2200,1,0,0:0:0:0:
+304,96,2500,1,0,0:0:0:0:
+352,136,2800,1,0,0:0:0:0:
+400,176,3100,1,0,0:0:0:0:
+64,216,3400,1,0,0:0:0:0:
+112,256,3700,1,0,0:0:0:0:
+160,96,4000,1,0,0:0:0:0:
+208,136,4300,1,0,0:0:0:0:
+256,176,4600,1,0,0:0:0:0:
+304,216,4900,1,0,0:0:0:0:
+352,256,5200,1,0,0:0:0:0:
+400,96,5500,1,0,0:0:0:0:
+64,136,5800,1,0,0:0:0:0:
+112,176,6100,1,0,0:0:0:0:
+160,216,6400,1,0,0:0:0:0:
+208,256,6700,1,0,0:0:0:0:
+256,96,7000,1,0,0:0:0:0:
+304,136,7300,1,0,0:0:0:0:
+352,176,7600,1,0,0:0:0:0:
+400,216,7900,1,0,0:0:0:0:
+64,256,8200,1,0,0:0:0:0:
+112,96,8500,1,0,0:0:0:0:
+160,136,8800,1,0,0:0:0:0:
+208,176,9100,1,0,0:0:0:0:
+256,216,9400,1,0,0:0:0:0:
+304,256,9700,1,0,0:0:0:0:
+352,96,10000,1,0,0:0:0:0:
+400,136,10300,1,0,0:0:0:0:
+64,176,10600,1,0,0:0:0:0:
+112,216,10900,1,0,0:0:0:0:
+160,256,11200,1,0,0:0:0:0:
+208,96,11500,1,0,0:0:0:0:
+256,136,11800,1,0,0:0:0:0:
+304,176,12100,1,0,0:0:0:0:
+352,216,12400,1,0,0:0:0:0:
+400,256,12700,1,0,0:0:0:0:
+";
+
+const DT: u32 = 64;
+
+fn map() -> rosu_pp::Beatmap {
+    rosu_pp::Beatmap::from_bytes(MAP.as_bytes()).unwrap()
+}
+
+#[test]
+fn double_time_is_harder() {
+    let map = map();
+    let nm = difficulty_attributes_from_beatmap(&map, &BeatmapDifficultySettings::default());
+    let dt = difficulty_attributes_from_beatmap(&map, &BeatmapDifficultySettings::with_mods(DT));
+
+    assert!(nm.stars > 0.0);
+    assert!(dt.stars > nm.stars);
+    assert_eq!(dt.max_combo, nm.max_combo);
+}
+
+#[test]
+fn clock_rate_overrides_the_mods() {
+    let map = map();
+    let dt = difficulty_attributes_from_beatmap(&map, &BeatmapDifficultySettings::with_mods(DT));
+    let settings = BeatmapDifficultySettings {
+        clock_rate: Some(1.0),
+        ..BeatmapDifficultySettings::with_mods(DT)
+    };
+    let nm = difficulty_attributes_from_beatmap(&map, &settings);
+
+    assert!(dt.stars > nm.stars);
+}
+
+#[test]
+fn converted_to_taiko() {
+    let map = map();
+    let settings = BeatmapDifficultySettings::default();
+    let taiko = difficulty_attributes_for_mode(&map, GameMode::Taiko, &settings).unwrap();
+
+    assert!(taiko.stars > 0.0);
+    assert!(difficulty_attributes_for_mode(&map, GameMode::Unknown, &settings).is_err());
+}
+
+#[test]
+fn pp_grows_with_accuracy() {
+    let accuracies = [90.0, 95.0, 98.0, 99.0, 100.0];
+    let perf = performance_from_beatmap(&map(), &BeatmapDifficultySettings::default(), &accuracies);
+
+    assert_eq!(perf.pp.len(), accuracies.len());
+    for (pp, accuracy) in perf.pp.iter().zip(accuracies) {
+        assert_eq!(pp.accuracy, accuracy);
+    }
+    assert!(perf.pp.windows(2).all(|w| w[0].pp < w[1].pp));
+
+    let dt = performance_from_beatmap(&map(), &BeatmapDifficultySettings::with_mods(DT), &[100.0]);
+    assert!(dt.difficulty.stars > perf.difficulty.stars);
+    assert!(dt.pp[0].pp > perf.pp[4].pp);
+}
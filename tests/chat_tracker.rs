@@ -0,0 +1,106 @@
+use rosu_memory_lib::reader::chat::common::{ChatChannel, ChatMessage};
+use rosu_memory_lib::reader::chat::tracker::ChatTracker;
+
+fn message(time: &str, sender: &str, content: &str) -> ChatMessage {
+    ChatMessage {
+        time: time.to_string(),
+        sender: sender.to_string(),
+        content: content.to_string(),
+    }
+}
+
+fn channel(name: &str, messages: Vec<ChatMessage>) -> ChatChannel {
+    ChatChannel {
+        name: name.to_string(),
+        messages,
+    }
+}
+
+fn contents(tracker: &mut ChatTracker, channels: &[ChatChannel]) -> Vec<String> {
+    tracker
+        .push(channels)
+        .into_iter()
+        .map(|m| format!("{}:{}", m.channel, m.message.content))
+        .collect()
+}
+
+#[test]
+fn only_new_messages_are_returned() {
+    let a = message("12:00", "a", "hi");
+    let b = message("12:01", "b", "hello");
+    let c = message("12:01", "a", "gl");
+    let mut tracker = ChatTracker::new();
+    assert_eq!(
+        contents(&mut tracker, &[channel("#osu", vec![a.clone(), b.clone()])]),
+        ["#osu:hi", "#osu:hello"]
+    );
+    assert!(contents(&mut tracker, &[channel("#osu", vec![a.clone(), b.clone()])]).is_empty());
+    assert_eq!(
+        contents(&mut tracker, &[channel("#osu", vec![a, b, c])]),
+        ["#osu:gl"]
+    );
+}
+
+#[test]
+fn oldest_messages_dropped_by_osu() {
+    let m: Vec<_> = (0..5)
+        .map(|i| message("12:00", "a", &i.to_string()))
+        .collect();
+    let mut tracker = ChatTracker::new();
+    tracker.push(&[channel("#osu", m[0..3].to_vec())]);
+    // same count, the window moved by 2
+    assert_eq!(
+        contents(&mut tracker, &[channel("#osu", m[2..5].to_vec())]),
+        ["#osu:3", "#osu:4"]
+    );
+}
+
+#[test]
+fn repeated_message_is_new() {
+    let spam = message("12:00", "a", "spam");
+    let mut tracker = ChatTracker::new();
+    tracker.push(&[channel("#osu", vec![spam.clone()])]);
+    assert_eq!(
+        contents(
+            &mut tracker,
+            &[channel("#osu", vec![spam.clone(), spam.clone()])]
+        ),
+        ["#osu:spam"]
+    );
+    assert!(contents(&mut tracker, &[channel("#osu", vec![spam.clone(), spam])]).is_empty());
+}
+
+#[test]
+fn channels_are_tracked_separately() {
+    let a = message("12:00", "a", "hi");
+    let mut tracker = ChatTracker::new();
+    tracker.push(&[channel("#osu", vec![a.clone()])]);
+    assert_eq!(
+        contents(
+            &mut tracker,
+            &[
+                channel("#osu", vec![a.clone()]),
+                channel("#multiplayer", vec![a])
+            ]
+        ),
+        ["#multiplayer:hi"]
+    );
+}
+
+#[test]
+fn header_is_split_into_time_and_sender() {
+    let hi = || "hi".to_string();
+    assert_eq!(
+        ChatMessage::from_header("12:34 peppy:", hi()),
+        message("12:34", "peppy", "hi")
+    );
+    // usernames can have spaces
+    assert_eq!(
+        ChatMessage::from_header("09:05 Some Player:", hi()),
+        message("09:05", "Some Player", "hi")
+    );
+    assert_eq!(
+        ChatMessage::from_header("BanchoBot:", hi()),
+        message("", "BanchoBot", "hi")
+    );
+}
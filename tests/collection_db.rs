@@ -0,0 +1,105 @@
+use rosu_memory_lib::db::collection::{Collection, CollectionDb};
+
+const FIXTURE: &[u8] = include_bytes!("fixtures/collection.db");
+/// An unnamed collection and a beatmap without md5 written as absent strings,
+/// then a collection whose name and only md5 are present but empty
+const MISSING_STRINGS: &[u8] = include_bytes!("fixtures/collection_missing.db");
+
+#[test]
+fn parse_fixture() {
+    let db = CollectionDb::from_bytes(FIXTURE).unwrap();
+    assert_eq!(db.version, 20250107);
+    assert_eq!(db.collections.len(), 4);
+
+    let farm = db.get("Farm").unwrap();
+    assert_eq!(farm.beatmaps.len(), 5);
+    assert_eq!(
+        farm.beatmaps[0].as_deref(),
+        Some("cfcd208495d565ef66e7dff9f98764da")
+    );
+
+    assert_eq!(db.get("ストリーム 練習").unwrap().beatmaps.len(), 6);
+    assert!(db.get("empty").unwrap().beatmaps.is_empty());
+    // name longer than 127 bytes uses a multi-byte ULEB128 length
+    assert_eq!(db.get(&"x".repeat(200)).unwrap().beatmaps.len(), 1);
+}
+
+#[test]
+fn round_trip_is_byte_for_byte() {
+    let db = CollectionDb::from_bytes(FIXTURE).unwrap();
+    assert_eq!(db.to_bytes(), FIXTURE);
+}
+
+#[test]
+fn missing_strings_round_trip() {
+    let db = CollectionDb::from_bytes(MISSING_STRINGS).unwrap();
+    assert_eq!(db.collections.len(), 3);
+
+    let unnamed = &db.collections[0];
+    assert_eq!(unnamed.name, None);
+    assert_eq!(
+        unnamed.beatmaps,
+        [Some("cfcd208495d565ef66e7dff9f98764da".to_string()), None]
+    );
+    assert_eq!(db.get("Farm").unwrap().beatmaps.len(), 1);
+
+    let empty = &db.collections[2];
+    assert_eq!(empty.name.as_deref(), Some(""));
+    assert_eq!(empty.beatmaps, [Some(String::new())]);
+
+    // unchanged, absent and empty strings are written back as they were read
+    assert_eq!(db.to_bytes(), MISSING_STRINGS);
+}
+
+#[test]
+fn round_trip_through_file() {
+    let db = CollectionDb::from_bytes(FIXTURE).unwrap();
+    let path = std::env::temp_dir().join(format!("rosu-collection-{}.db", std::process::id()));
+    db.write_to_path(&path).unwrap();
+    let written = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(written, FIXTURE);
+    assert_eq!(CollectionDb::from_bytes(&written).unwrap(), db);
+}
+
+#[test]
+fn containing_cross_references_md5() {
+    let db = CollectionDb::from_bytes(FIXTURE).unwrap();
+    let names: Vec<&str> = db
+        .containing("eccbc87e4b5ce2fe28308fd9f2a7baf3")
+        .map(|c| c.name())
+        .collect();
+    assert_eq!(names, ["Farm", "ストリーム 練習"]);
+    assert_eq!(db.containing("not a md5").count(), 0);
+}
+
+#[test]
+fn add_and_remove() {
+    let mut db = CollectionDb::from_bytes(FIXTURE).unwrap();
+    let md5 = "a1d0c6e83f027327d8461063f4ac58a6";
+
+    assert!(db.add("Farm", md5));
+    assert!(!db.add("Farm", md5));
+    assert!(db.get("Farm").unwrap().contains(md5));
+
+    assert!(db.add("new", md5));
+    assert_eq!(
+        db.collections.last(),
+        Some(&Collection {
+            name: Some("new".to_string()),
+            beatmaps: vec![Some(md5.to_string())],
+        })
+    );
+
+    assert!(db.remove("Farm", md5));
+    assert!(!db.remove("Farm", md5));
+    assert!(!db.remove("missing", md5));
+
+    // reparsing what we wrote gives back the same collections
+    assert_eq!(CollectionDb::from_bytes(&db.to_bytes()).unwrap(), db);
+}
+
+#[test]
+fn truncated_data_is_an_error() {
+    assert!(CollectionDb::from_bytes(&FIXTURE[..FIXTURE.len() - 1]).is_err());
+}
@@ -0,0 +1,151 @@
+use rosu_memory_lib::reader::common::{GameMode, Grade};
+use rosu_memory_lib::reader::structs::Hit;
+use rosu_memory_lib::scoring::calculate_grade;
+
+const HD: u32 = 1 << 3;
+const FL: u32 = 1 << 10;
+const FADE_IN: u32 = 1 << 20;
+const HR: u32 = 1 << 4;
+
+fn hits(n300: i16, n100: i16, n50: i16, misses: i16) -> Hit {
+    Hit {
+        _300: n300,
+        _100: n100,
+        _50: n50,
+        _miss: misses,
+        ..Default::default()
+    }
+}
+
+fn mania(max: i16, n300: i16, n200: i16, n100: i16, n50: i16, misses: i16) -> Hit {
+    Hit {
+        _geki: max,
+        _300: n300,
+        _katu: n200,
+        _100: n100,
+        _50: n50,
+        _miss: misses,
+    }
+}
+
+fn catch(fruits: i16, droplets: i16, tiny_droplets: i16, tiny_misses: i16, misses: i16) -> Hit {
+    Hit {
+        _300: fruits,
+        _100: droplets,
+        _50: tiny_droplets,
+        _katu: tiny_misses,
+        _miss: misses,
+        ..Default::default()
+    }
+}
+
+fn grade(mode: GameMode, hit: Hit, mods: u32) -> Grade {
+    calculate_grade(&mode, &hit, mods).unwrap()
+}
+
+#[test]
+fn empty_play_is_ss() {
+    for mode in [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ] {
+        assert_eq!(grade(mode, Hit::default(), 0), Grade::X);
+        assert_eq!(grade(mode, Hit::default(), HD), Grade::XH);
+    }
+}
+
+#[test]
+fn osu_grades() {
+    let osu = |hit| grade(GameMode::Osu, hit, 0);
+    assert_eq!(osu(hits(100, 0, 0, 0)), Grade::X);
+    // S needs over 90% 300s, under 1% 50s and no miss
+    assert_eq!(osu(hits(91, 9, 0, 0)), Grade::S);
+    assert_eq!(osu(hits(90, 10, 0, 0)), Grade::A);
+    assert_eq!(osu(hits(991, 0, 9, 0)), Grade::S);
+    assert_eq!(osu(hits(990, 0, 10, 0)), Grade::A);
+    assert_eq!(osu(hits(99, 0, 0, 1)), Grade::A);
+    // A: over 80% 300s without miss or over 90% 300s
+    assert_eq!(osu(hits(81, 19, 0, 0)), Grade::A);
+    assert_eq!(osu(hits(80, 20, 0, 0)), Grade::B);
+    assert_eq!(osu(hits(91, 8, 0, 1)), Grade::A);
+    assert_eq!(osu(hits(89, 10, 0, 1)), Grade::B);
+    // B: over 70% 300s without miss or over 80% 300s
+    assert_eq!(osu(hits(71, 29, 0, 0)), Grade::B);
+    assert_eq!(osu(hits(70, 30, 0, 0)), Grade::C);
+    assert_eq!(osu(hits(81, 18, 0, 1)), Grade::B);
+    assert_eq!(osu(hits(79, 20, 0, 1)), Grade::C);
+    // C: over 60% 300s
+    assert_eq!(osu(hits(61, 38, 0, 1)), Grade::C);
+    assert_eq!(osu(hits(60, 39, 0, 1)), Grade::D);
+    assert_eq!(osu(hits(0, 0, 0, 10)), Grade::D);
+}
+
+#[test]
+fn osu_silver_grades() {
+    assert_eq!(grade(GameMode::Osu, hits(100, 0, 0, 0), HD), Grade::XH);
+    assert_eq!(grade(GameMode::Osu, hits(100, 0, 0, 0), FL), Grade::XH);
+    assert_eq!(grade(GameMode::Osu, hits(95, 5, 0, 0), HD | HR), Grade::SH);
+    assert_eq!(grade(GameMode::Osu, hits(95, 5, 0, 0), HR), Grade::S);
+    // only SS and S have silver variants
+    assert_eq!(grade(GameMode::Osu, hits(85, 15, 0, 0), HD), Grade::A);
+}
+
+#[test]
+fn taiko_grades() {
+    let taiko = |hit| grade(GameMode::Taiko, hit, 0);
+    assert_eq!(taiko(hits(500, 0, 0, 0)), Grade::X);
+    assert_eq!(taiko(hits(91, 9, 0, 0)), Grade::S);
+    assert_eq!(taiko(hits(95, 4, 0, 1)), Grade::A);
+    assert_eq!(taiko(hits(81, 19, 0, 0)), Grade::A);
+    assert_eq!(taiko(hits(75, 25, 0, 0)), Grade::B);
+    assert_eq!(taiko(hits(65, 35, 0, 0)), Grade::C);
+    assert_eq!(taiko(hits(50, 50, 0, 0)), Grade::D);
+    assert_eq!(grade(GameMode::Taiko, hits(95, 5, 0, 0), FL), Grade::SH);
+}
+
+#[test]
+fn catch_grades() {
+    let ctb = |hit| grade(GameMode::Catch, hit, 0);
+    assert_eq!(ctb(catch(100, 20, 80, 0, 0)), Grade::X);
+    // thresholds are strict: 98% is an A
+    assert_eq!(ctb(catch(90, 0, 9, 0, 1)), Grade::S);
+    assert_eq!(ctb(catch(98, 0, 0, 0, 2)), Grade::A);
+    assert_eq!(ctb(catch(95, 0, 0, 0, 5)), Grade::A);
+    assert_eq!(ctb(catch(94, 0, 0, 2, 4)), Grade::B);
+    assert_eq!(ctb(catch(90, 0, 0, 0, 10)), Grade::C);
+    assert_eq!(ctb(catch(86, 0, 0, 0, 14)), Grade::C);
+    assert_eq!(ctb(catch(85, 0, 0, 0, 15)), Grade::D);
+    // missed tiny droplets lower the accuracy but are not misses
+    assert_eq!(ctb(catch(100, 0, 197, 3, 0)), Grade::S);
+    assert_eq!(grade(GameMode::Catch, catch(99, 0, 0, 0, 1), HD), Grade::SH);
+}
+
+#[test]
+fn mania_grades() {
+    let m = |hit| grade(GameMode::Mania, hit, 0);
+    assert_eq!(m(mania(50, 50, 0, 0, 0, 0)), Grade::X);
+    // 200s count as 2/3 of a 300
+    assert_eq!(m(mania(0, 88, 12, 0, 0, 0)), Grade::S);
+    assert_eq!(m(mania(0, 85, 15, 0, 0, 0)), Grade::A);
+    assert_eq!(m(mania(0, 95, 0, 0, 0, 5)), Grade::A);
+    assert_eq!(m(mania(0, 90, 0, 0, 0, 10)), Grade::B);
+    assert_eq!(m(mania(0, 81, 0, 0, 0, 19)), Grade::B);
+    assert_eq!(m(mania(0, 80, 0, 0, 0, 20)), Grade::C);
+    assert_eq!(m(mania(0, 71, 0, 0, 0, 29)), Grade::C);
+    assert_eq!(m(mania(0, 70, 0, 0, 0, 30)), Grade::D);
+    assert_eq!(
+        grade(GameMode::Mania, mania(10, 0, 0, 0, 0, 0), FADE_IN),
+        Grade::XH
+    );
+    assert_eq!(
+        grade(GameMode::Mania, mania(0, 99, 0, 1, 0, 0), HD),
+        Grade::SH
+    );
+}
+
+#[test]
+fn unknown_mode_is_an_error() {
+    assert!(calculate_grade(&GameMode::Unknown, &hits(1, 0, 0, 0), 0).is_err());
+}
@@ -0,0 +1,126 @@
+use rosu_memory_lib::reader::common::GameMode;
+use rosu_memory_lib::reader::gameplay::common::{HitErrorStats, HitJudgement, HitWindows};
+
+const EZ: u32 = 1 << 1;
+const HR: u32 = 1 << 4;
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-6,
+        "expected {expected}, got {actual}"
+    );
+}
+
+/// great, ok and meh windows
+fn osu_windows(od: f64, mods: u32) -> [f64; 3] {
+    let w = HitWindows::with_mods(GameMode::Osu, od, mods).unwrap();
+    assert_eq!((w.perfect, w.good), (None, None));
+    [w.great, w.ok, w.meh.unwrap()]
+}
+
+#[test]
+fn osu_hit_windows() {
+    for (od, mods, expected) in [
+        (0.0, 0, [80.0, 140.0, 200.0]),
+        (5.0, 0, [50.0, 100.0, 150.0]),
+        (10.0, 0, [20.0, 60.0, 100.0]),
+        // OD 7
+        (5.0, HR, [38.0, 84.0, 130.0]),
+        // capped at OD 10
+        (10.0, HR, [20.0, 60.0, 100.0]),
+        (0.0, HR, [80.0, 140.0, 200.0]),
+        // OD 2.5
+        (5.0, EZ, [65.0, 120.0, 175.0]),
+        (10.0, EZ, [50.0, 100.0, 150.0]),
+    ] {
+        let windows = osu_windows(od, mods);
+        for (actual, expected) in windows.into_iter().zip(expected) {
+            assert_close(actual, expected);
+        }
+    }
+}
+
+#[test]
+fn taiko_hit_windows() {
+    for (od, mods, great, ok) in [
+        (0.0, 0, 50.0, 120.0),
+        (5.0, 0, 35.0, 80.0),
+        (10.0, 0, 20.0, 50.0),
+        (5.0, HR, 29.0, 68.0),
+        (10.0, EZ, 35.0, 80.0),
+    ] {
+        let w = HitWindows::with_mods(GameMode::Taiko, od, mods).unwrap();
+        assert_close(w.great, great);
+        assert_close(w.ok, ok);
+        assert_eq!(w.meh, None);
+    }
+}
+
+#[test]
+fn mania_hit_windows() {
+    let w = HitWindows::with_mods(GameMode::Mania, 5.0, 0).unwrap();
+    assert_eq!(w.perfect, Some(16.0));
+    assert_eq!([w.great, w.good.unwrap(), w.ok], [49.0, 82.0, 112.0]);
+    assert_eq!(w.meh, Some(136.0));
+
+    // mods scale the windows, not the OD
+    let hr = HitWindows::with_mods(GameMode::Mania, 5.0, HR).unwrap();
+    assert_close(hr.perfect.unwrap(), 16.0 / 1.4);
+    assert_close(hr.great, 35.0);
+    let ez = HitWindows::with_mods(GameMode::Mania, 5.0, EZ).unwrap();
+    assert_close(ez.great, 49.0 * 1.4);
+    assert_close(ez.meh.unwrap(), 136.0 * 1.4);
+
+    assert!(HitWindows::with_mods(GameMode::Catch, 5.0, 0).is_none());
+}
+
+#[test]
+fn judge_by_absolute_error() {
+    let w = HitWindows::new(GameMode::Osu, 5.0).unwrap();
+    assert_eq!(w.judge(0), Some(HitJudgement::Great));
+    assert_eq!(w.judge(-50), Some(HitJudgement::Great));
+    assert_eq!(w.judge(51), Some(HitJudgement::Ok));
+    assert_eq!(w.judge(-150), Some(HitJudgement::Meh));
+    assert_eq!(w.judge(151), None);
+
+    let mania = HitWindows::new(GameMode::Mania, 5.0).unwrap();
+    assert_eq!(mania.judge(-16), Some(HitJudgement::Perfect));
+    assert_eq!(mania.judge(60), Some(HitJudgement::Good));
+}
+
+#[test]
+fn histograms_by_judgement() {
+    let w = HitWindows::new(GameMode::Osu, 5.0).unwrap();
+    let histograms = w.histograms(&[-12, -3, 4, 9, 80, 300], 10);
+    assert_eq!(histograms.len(), 2);
+
+    let great = &histograms[0];
+    assert_eq!(great.judgement, HitJudgement::Great);
+    assert_eq!(great.count, 4);
+    let buckets: Vec<_> = great.buckets.iter().map(|(&k, &v)| (k, v)).collect();
+    assert_eq!(buckets, [(-20, 1), (-10, 1), (0, 2)]);
+
+    assert_eq!(histograms[1].judgement, HitJudgement::Ok);
+    assert_eq!(histograms[1].count, 1);
+}
+
+#[test]
+fn unstable_rate_of_known_errors() {
+    let stats = HitErrorStats::new(&[-10, 0, 10, 20], 1.0);
+    assert_eq!(stats.count, 4);
+    assert_close(stats.mean, 5.0);
+    assert_eq!(stats.early_mean, Some(-10.0));
+    assert_eq!(stats.late_mean, Some(10.0));
+    // standard deviation of sqrt(125)
+    assert_close(stats.unstable_rate, 125f64.sqrt() * 10.0);
+
+    // DT errors are in game time
+    let dt = HitErrorStats::new(&[-10, 0, 10, 20], 1.5);
+    assert_close(dt.unstable_rate, 125f64.sqrt() * 10.0 / 1.5);
+
+    let constant = HitErrorStats::new(&[7, 7, 7], 1.0);
+    assert_eq!(constant.unstable_rate, 0.0);
+    assert_eq!(constant.early_mean, None);
+
+    assert_eq!(HitErrorStats::new(&[], 1.0), HitErrorStats::default());
+}
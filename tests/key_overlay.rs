@@ -0,0 +1,107 @@
+use std::time::{Duration, Instant};
+
+use rosu_memory_lib::reader::gameplay::common::{KeyOverlayState, KeyState};
+use rosu_memory_lib::reader::gameplay::key_overlay::{Key, KeyEventKind, KeyOverlay};
+
+fn keys(k1: (bool, i32), k2: (bool, i32)) -> KeyOverlayState {
+    KeyOverlayState {
+        k1: KeyState {
+            pressed: k1.0,
+            count: k1.1,
+        },
+        k2: KeyState {
+            pressed: k2.0,
+            count: k2.1,
+        },
+        ..Default::default()
+    }
+}
+
+fn events(
+    overlay: &mut KeyOverlay,
+    state: KeyOverlayState,
+    at: Instant,
+) -> Vec<(Key, KeyEventKind)> {
+    overlay
+        .push(state, 0, at)
+        .into_iter()
+        .map(|e| (e.key, e.kind))
+        .collect()
+}
+
+#[test]
+fn press_and_release() {
+    use KeyEventKind::*;
+
+    let start = Instant::now();
+    let mut overlay = KeyOverlay::default();
+    assert!(events(&mut overlay, keys((false, 0), (false, 0)), start).is_empty());
+    assert_eq!(
+        events(&mut overlay, keys((true, 1), (false, 0)), start),
+        [(Key::K1, Press)]
+    );
+    // held
+    assert!(events(&mut overlay, keys((true, 1), (false, 0)), start).is_empty());
+    assert_eq!(
+        events(&mut overlay, keys((false, 1), (true, 1)), start),
+        [(Key::K1, Release), (Key::K2, Press)]
+    );
+    assert_eq!(overlay.keys(), Some(keys((false, 1), (true, 1))));
+}
+
+#[test]
+fn presses_between_reads_are_counted() {
+    use KeyEventKind::*;
+
+    let start = Instant::now();
+    let mut overlay = KeyOverlay::default();
+    events(&mut overlay, keys((true, 1), (false, 0)), start);
+    // released, pressed twice and still held
+    assert_eq!(
+        events(&mut overlay, keys((true, 3), (false, 0)), start),
+        [
+            (Key::K1, Release),
+            (Key::K1, Press),
+            (Key::K1, Release),
+            (Key::K1, Press)
+        ]
+    );
+}
+
+#[test]
+fn restart_is_not_a_press() {
+    let start = Instant::now();
+    let mut overlay = KeyOverlay::default();
+    events(&mut overlay, keys((false, 0), (false, 0)), start);
+    events(&mut overlay, keys((false, 10), (false, 10)), start);
+    assert!(events(&mut overlay, keys((false, 0), (false, 0)), start).is_empty());
+    assert_eq!(overlay.kps(), 0.0);
+}
+
+#[test]
+fn kps_over_the_window() {
+    let start = Instant::now();
+    let at = |ms: u64| start + Duration::from_millis(ms);
+    let mut overlay = KeyOverlay::new(Duration::from_millis(500));
+    events(&mut overlay, keys((false, 0), (false, 0)), at(0));
+    for i in 1..=4 {
+        events(
+            &mut overlay,
+            keys((false, i), (false, 0)),
+            at(i as u64 * 100),
+        );
+    }
+    // 4 presses in half a second
+    assert_eq!(overlay.kps(), 8.0);
+    assert_eq!(overlay.bpm(), 120.0);
+
+    // only the kps of the reads matters, not when it is asked
+    std::thread::sleep(Duration::from_millis(10));
+    assert_eq!(overlay.kps(), 8.0);
+
+    // the presses at 100 and 200ms are out of the window
+    events(&mut overlay, keys((false, 4), (false, 1)), at(750));
+    assert_eq!(overlay.kps(), 6.0);
+    events(&mut overlay, keys((false, 4), (false, 1)), at(2000));
+    assert_eq!(overlay.kps(), 0.0);
+}
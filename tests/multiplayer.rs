@@ -0,0 +1,58 @@
+use rosu_memory_lib::reader::multiplayer::common::{
+    MultiplayerRoom, MultiplayerSlot, SlotStatus, Team, TeamMode,
+};
+
+fn slot(index: usize, status: i32, team: i32, user_id: i32, player: &str) -> MultiplayerSlot {
+    let status = SlotStatus::from(status);
+    MultiplayerSlot {
+        index,
+        status,
+        team: Team::from(team),
+        user_id,
+        player: status.has_player().then(|| player.to_string()),
+        mods: 0,
+    }
+}
+
+fn room(slots: Vec<MultiplayerSlot>) -> MultiplayerRoom {
+    MultiplayerRoom {
+        host_id: 2,
+        team_mode: TeamMode::from(2),
+        slots,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn players_skip_empty_and_locked_slots() {
+    let room = room(vec![
+        slot(0, 8, 1, 1, "blue"),
+        slot(1, 4, 2, 2, "red"),
+        slot(2, 1, 0, 0, ""),
+        slot(3, 2, 0, 0, ""),
+        slot(4, 16, 2, 3, "no map"),
+    ]);
+    assert_eq!(room.players().count(), 3);
+    assert_eq!(room.host_name(), Some("red"));
+    assert_eq!(room.team(Team::Red).count(), 2);
+    assert_eq!(room.team(Team::Blue).count(), 1);
+    assert!(room.team_mode.has_teams());
+    assert!(!room.is_everyone_ready());
+}
+
+#[test]
+fn everyone_ready() {
+    let ready = room(vec![
+        slot(0, 8, 0, 1, "a"),
+        slot(1, 8, 0, 2, "b"),
+        slot(2, 1, 0, 0, ""),
+    ]);
+    assert!(ready.is_everyone_ready());
+    assert!(!room(vec![slot(0, 1, 0, 0, "")]).is_everyone_ready());
+}
+
+#[test]
+fn host_left() {
+    let room = room(vec![slot(0, 8, 0, 1, "a"), slot(1, 128, 0, 2, "b")]);
+    assert!(room.host().is_none());
+}
@@ -0,0 +1,114 @@
+use std::path::PathBuf;
+
+use rosu_memory_lib::reader::leaderboard::common::LeaderboardType;
+use rosu_memory_lib::reader::settings::common::OsuKey;
+use rosu_memory_lib::reader::settings::config::{FrameSync, OsuConfig};
+use rosu_memory_lib::reader::wine::{WineKind, WinePrefix};
+
+const USER_CFG: &str = "\u{feff}# osu! configuration for peppy
+# last updated on Monday, 1 January 2024
+
+BeatmapDirectory = Songs
+Skin = - Custom Skin -
+Username = peppy
+VolumeUniversal = 60
+VolumeMusic = 40
+VolumeEffect = 25
+Offset = -12
+CursorSize = 0.75
+DimLevel = 90
+RankType = Country
+FrameSync = Custom
+CustomFrameLimit = 480
+keyOsuLeft = D
+keyOsuRight = F
+keyFruitsDash = Space
+ShowInterface = 0
+";
+
+#[test]
+fn parse_user_config() {
+    let config = OsuConfig::parse(USER_CFG);
+    assert_eq!(config.skin(), Some("- Custom Skin -"));
+    assert_eq!(config.username(), Some("peppy"));
+    assert_eq!(config.beatmap_directory(), "Songs");
+    assert_eq!(config.get_bool("ShowInterface"), Some(false));
+    assert_eq!(config.frame_sync(), Some(FrameSync::Custom));
+    assert_eq!(config.fps_limit(), Some(480));
+
+    let settings = config.settings();
+    assert_eq!(settings.volume.master, 60);
+    assert_eq!(settings.volume.music, 40);
+    assert_eq!(settings.volume.effect, 25);
+    assert_eq!(settings.offset, -12);
+    assert_eq!(settings.cursor_size, 0.75);
+    assert_eq!(settings.background_dim, 90);
+    assert_eq!(settings.leaderboard_type, LeaderboardType::Country);
+    assert_eq!(settings.skin, "- Custom Skin -");
+
+    let keys = settings.key_bindings;
+    assert_eq!(keys.osu_left, OsuKey::from_name("D").unwrap());
+    assert_eq!(keys.osu_right, OsuKey::from_name("F").unwrap());
+    assert_eq!(keys.catch_dash, OsuKey::from_name("Space").unwrap());
+    // missing keys use the osu! defaults
+    assert_eq!(keys.taiko_outer_right, OsuKey::from_name("V").unwrap());
+}
+
+#[test]
+fn songs_path_is_joined_to_the_osu_folder() {
+    let mut config = OsuConfig::parse("BeatmapDirectory = Songs");
+    assert_eq!(config.songs_path(), PathBuf::from("Songs"));
+
+    config.dir = Some(PathBuf::from("/games/osu"));
+    assert_eq!(config.songs_path(), PathBuf::from("/games/osu/Songs"));
+
+    let config = OsuConfig::parse("BeatmapDirectory = /mnt/maps");
+    assert_eq!(config.songs_path(), PathBuf::from("/mnt/maps"));
+}
+
+#[test]
+fn songs_path_is_translated_with_the_wine_prefix() {
+    let prefix = PathBuf::from("/nonexistent/prefix");
+    let mut config = OsuConfig::parse(r"BeatmapDirectory = C:\Maps\Songs");
+    config.dir = Some(PathBuf::from("/games/osu"));
+    config.prefix = Some(WinePrefix::new(WineKind::Wine, &prefix));
+    assert_eq!(config.songs_path(), prefix.join("drive_c/Maps/Songs"));
+
+    // relative paths are still joined to the osu! folder
+    let mut config = OsuConfig::parse("BeatmapDirectory = Songs");
+    config.dir = Some(PathBuf::from("/games/osu"));
+    config.prefix = Some(WinePrefix::new(WineKind::Wine, &prefix));
+    assert_eq!(config.songs_path(), PathBuf::from("/games/osu/Songs"));
+}
+
+#[test]
+fn load_merges_user_config() {
+    let dir = std::env::temp_dir().join(format!("osu-config-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("osu!.cfg"),
+        "Skin = Default\nFrameSync = Limit120\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("osu!.peppy.cfg"), USER_CFG).unwrap();
+
+    let config = OsuConfig::load(&dir, Some("peppy")).unwrap();
+    let latest = OsuConfig::load(&dir, None).unwrap();
+    let global = OsuConfig::load(&dir, Some("nobody")).unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert_eq!(config.skin(), Some("- Custom Skin -"));
+    assert_eq!(config.songs_path(), dir.join("Songs"));
+    assert_eq!(latest.skin(), Some("- Custom Skin -"));
+    assert_eq!(global.skin(), Some("Default"));
+    assert_eq!(global.fps_limit(), Some(120));
+}
+
+#[test]
+fn load_without_config_fails() {
+    let dir = std::env::temp_dir().join(format!("osu-config-empty-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let result = OsuConfig::load(&dir, None);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(result.is_err());
+}
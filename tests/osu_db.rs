@@ -0,0 +1,108 @@
+use std::path::PathBuf;
+
+use rosu_memory_lib::db::osu::{OsuDb, OsuDbBeatmap};
+use rosu_memory_lib::reader::common::GameMode;
+
+const CURRENT: &[u8] = include_bytes!("fixtures/osu.db");
+/// entry sizes and double star ratings
+const V2019: &[u8] = include_bytes!("fixtures/osu_2019.db");
+/// entry sizes, byte difficulties, no star ratings nor user permissions
+const OLD: &[u8] = include_bytes!("fixtures/osu_old.db");
+
+fn check_common(db: &OsuDb) {
+    assert_eq!(db.folder_count, 12);
+    assert!(db.account_unlocked);
+    assert_eq!(db.player_name, "peppy");
+    assert_eq!(db.beatmaps.len(), 2);
+
+    let first = &db.beatmaps[0];
+    assert_eq!(first.artist, "Artist");
+    assert_eq!(first.artist_unicode, "");
+    assert_eq!(first.title_unicode, "タイトル");
+    assert_eq!(first.circle_count, 100);
+    assert_eq!(first.slider_velocity, 1.4);
+    assert_eq!(first.total_time, 95000);
+    assert_eq!(first.timing_points.len(), 2);
+    assert_eq!(first.timing_points[0].bpm, 180.0);
+    assert!(!first.timing_points[1].uninherited);
+    assert_eq!(first.id, 1000);
+    assert_eq!(first.local_offset, -5);
+    assert_eq!(first.stack_leniency, 0.7);
+    assert_eq!(first.mode, GameMode::Osu);
+    assert!(first.disable_storyboard);
+    assert_eq!(first.mania_scroll_speed, 20);
+    assert_eq!(first.relative_path(), PathBuf::from("folder 0/map 0.osu"));
+
+    // the second entry is only right if the first one was read to its end
+    let second: &OsuDbBeatmap = db.find_by_id(1001).unwrap();
+    assert_eq!(second.title, "Second");
+    assert_eq!(second.mode, GameMode::Mania);
+    assert_eq!(second.folder, "folder 1");
+}
+
+#[test]
+fn current_version() {
+    let db = OsuDb::from_bytes(CURRENT).unwrap();
+    assert_eq!(db.version, 20250107);
+    check_common(&db);
+    assert_eq!(db.user_permissions, 5);
+
+    let first = &db.beatmaps[0];
+    assert_eq!(first.ar, 9.3);
+    assert_eq!(first.od, 8.5);
+    assert_eq!(first.star_ratings.stars(GameMode::Osu, 0), Some(5.25));
+    assert_eq!(first.star_ratings.stars(GameMode::Osu, 64), Some(7.5));
+    assert_eq!(first.star_ratings.stars(GameMode::Taiko, 0), None);
+}
+
+#[test]
+fn version_with_entry_sizes_and_double_stars() {
+    let db = OsuDb::from_bytes(V2019).unwrap();
+    assert_eq!(db.version, 20191105);
+    check_common(&db);
+    assert_eq!(
+        db.beatmaps[0].star_ratings.stars(GameMode::Osu, 64),
+        Some(7.5)
+    );
+    assert_eq!(db.beatmaps[1].cs, 4.2);
+}
+
+#[test]
+fn old_version() {
+    let db = OsuDb::from_bytes(OLD).unwrap();
+    assert_eq!(db.version, 20131110);
+    check_common(&db);
+    assert_eq!(db.user_permissions, 0);
+
+    let first = &db.beatmaps[0];
+    // stored as bytes before floats
+    assert_eq!(first.ar, 9.0);
+    assert_eq!(first.od, 8.0);
+    assert!(first.star_ratings.for_mode(GameMode::Osu).is_empty());
+}
+
+#[test]
+fn search_and_lookup() {
+    let db = OsuDb::from_bytes(CURRENT).unwrap();
+    let found: Vec<_> = db.search("STREAM").map(|b| b.id).collect();
+    assert_eq!(found, [1000]);
+    assert_eq!(db.search("mapper").count(), 2);
+    assert_eq!(
+        db.find_by_md5("92eb5ffee6ae2fec3ad71c777531578f")
+            .unwrap()
+            .id,
+        1001
+    );
+    assert!(db.find_by_md5("missing").is_none());
+}
+
+#[test]
+fn corrupt_count_errors_without_allocating() {
+    let mut bytes = CURRENT.to_vec();
+    // beatmap count right after the header and the player name
+    let count_pos = 4 + 4 + 1 + 8 + 1 + 1 + "peppy".len();
+    bytes[count_pos..count_pos + 4].copy_from_slice(&i32::MAX.to_le_bytes());
+    assert!(OsuDb::from_bytes(&bytes).is_err());
+
+    assert!(OsuDb::from_bytes(&CURRENT[..CURRENT.len() / 2]).is_err());
+}
@@ -0,0 +1,228 @@
+use std::time::{Duration, Instant};
+
+use rosu_memory_lib::reader::common::{GameMode, GameState, PlayKind};
+use rosu_memory_lib::reader::gameplay::common::GameplayInfo;
+use rosu_memory_lib::reader::gameplay::session::{PlayEvent, PlaySession, PlaySnapshot};
+
+struct Driver {
+    session: PlaySession,
+    start: Instant,
+    now: Duration,
+    mode: GameMode,
+}
+
+impl Driver {
+    fn new() -> Self {
+        Self {
+            session: PlaySession::new(Duration::from_millis(150)),
+            start: Instant::now(),
+            now: Duration::ZERO,
+            mode: GameMode::Osu,
+        }
+    }
+
+    fn with_mode(mode: GameMode) -> Self {
+        Self {
+            mode,
+            ..Self::new()
+        }
+    }
+
+    /// Advances the clock by `step` ms and feeds a read
+    fn play(
+        &mut self,
+        step: u64,
+        md5: &str,
+        ig_time: i32,
+        hp: f64,
+        retries: i32,
+    ) -> Vec<PlayEvent> {
+        self.play_with_mods(step, md5, ig_time, hp, retries, 0)
+    }
+
+    fn play_with_mods(
+        &mut self,
+        step: u64,
+        md5: &str,
+        ig_time: i32,
+        hp: f64,
+        retries: i32,
+        mods: u32,
+    ) -> Vec<PlayEvent> {
+        self.now += Duration::from_millis(step);
+        let snapshot = PlaySnapshot {
+            beatmap_md5: md5.to_string(),
+            mode: self.mode,
+            info: GameplayInfo {
+                score: ig_time.max(0),
+                mods,
+                combo: 0,
+                max_combo: 0,
+                hp,
+                username: "player".to_string(),
+                ig_time,
+                retries,
+                hits: Default::default(),
+                kind: PlayKind::Play,
+            },
+        };
+        self.session
+            .push(GameState::Playing, Some(snapshot), self.start + self.now)
+    }
+
+    fn leave(&mut self, step: u64, game_state: GameState) -> Vec<PlayEvent> {
+        self.now += Duration::from_millis(step);
+        self.session.push(game_state, None, self.start + self.now)
+    }
+}
+
+fn names(events: &[PlayEvent]) -> Vec<&'static str> {
+    events
+        .iter()
+        .map(|e| match e {
+            PlayEvent::Started { .. } => "started",
+            PlayEvent::Paused { .. } => "paused",
+            PlayEvent::Resumed { .. } => "resumed",
+            PlayEvent::Failed(_) => "failed",
+            PlayEvent::Retried(_) => "retried",
+            PlayEvent::Quit(_) => "quit",
+            PlayEvent::Completed(_) => "completed",
+        })
+        .collect()
+}
+
+#[test]
+fn completed_play_with_a_pause() {
+    let mut d = Driver::new();
+    assert_eq!(names(&d.play(0, "a", -1000, 200.0, 0)), ["started"]);
+    assert!(d.play(50, "a", -950, 200.0, 0).is_empty());
+    assert!(d.play(50, "a", 100, 200.0, 0).is_empty());
+    // game time stops moving
+    assert!(d.play(100, "a", 100, 200.0, 0).is_empty());
+    assert_eq!(names(&d.play(100, "a", 100, 200.0, 0)), ["paused"]);
+    assert!(d.session.is_paused());
+    assert!(d.play(1000, "a", 100, 200.0, 0).is_empty());
+    assert_eq!(names(&d.play(50, "a", 150, 200.0, 0)), ["resumed"]);
+    assert!(d.play(50, "a", 200, 200.0, 0).is_empty());
+
+    let events = d.leave(50, GameState::ResultScreen);
+    assert_eq!(names(&events), ["completed"]);
+    let PlayEvent::Completed(result) = &events[0] else {
+        unreachable!()
+    };
+    assert_eq!(result.beatmap_md5, "a");
+    assert_eq!(result.info.ig_time, 200);
+    // 1450ms in total, 1250ms paused since the game time last moved
+    assert_eq!(result.play_time, Duration::from_millis(200));
+    assert!(!d.session.is_playing());
+}
+
+#[test]
+fn retry_ends_the_attempt_and_starts_a_new_one() {
+    let mut d = Driver::new();
+    d.play(0, "a", 0, 200.0, 0);
+    d.play(50, "a", 50, 200.0, 0);
+    let events = d.play(50, "a", -1000, 200.0, 1);
+    assert_eq!(names(&events), ["retried", "started"]);
+    let PlayEvent::Retried(result) = &events[0] else {
+        unreachable!()
+    };
+    assert_eq!(result.info.retries, 0);
+    assert_eq!(result.info.ig_time, 50);
+}
+
+#[test]
+fn fail_then_retry() {
+    let mut d = Driver::new();
+    d.play(0, "a", 0, 200.0, 0);
+    d.play(50, "a", 50, 100.0, 0);
+    assert_eq!(names(&d.play(50, "a", 100, 0.0, 0)), ["failed"]);
+    // fail screen, time stopped: no pause nor second fail
+    assert!(d.play(500, "a", 100, 0.0, 0).is_empty());
+    assert!(d.play(500, "a", 100, 0.0, 0).is_empty());
+    assert_eq!(names(&d.play(50, "a", -500, 200.0, 1)), ["started"]);
+}
+
+#[test]
+fn fail_then_quit_emits_nothing_more() {
+    let mut d = Driver::new();
+    d.play(0, "a", 0, 200.0, 0);
+    d.play(50, "a", 50, 0.0, 0);
+    assert!(d.leave(50, GameState::SongSelect).is_empty());
+}
+
+#[test]
+fn no_fail_never_fails() {
+    let mut d = Driver::new();
+    d.play_with_mods(0, "a", 0, 200.0, 0, 1);
+    assert!(d.play_with_mods(50, "a", 50, 0.0, 0, 1).is_empty());
+    assert_eq!(names(&d.leave(50, GameState::ResultScreen)), ["completed"]);
+}
+
+#[test]
+fn multiplayer_result_screens_complete() {
+    for result_screen in [
+        GameState::MultiplayerResultScreen,
+        GameState::MultiplayerResultScreenTagCoop,
+        GameState::MultiplayerResultScreenTeamVs,
+    ] {
+        let mut d = Driver::new();
+        d.play(0, "a", 0, 200.0, 0);
+        d.play(50, "a", 50, 200.0, 0);
+        assert_eq!(names(&d.leave(50, result_screen)), ["completed"]);
+    }
+}
+
+#[test]
+fn quit_from_pause() {
+    let mut d = Driver::new();
+    d.play(0, "a", 0, 200.0, 0);
+    d.play(50, "a", 50, 200.0, 0);
+    assert_eq!(names(&d.play(200, "a", 50, 200.0, 0)), ["paused"]);
+    assert_eq!(names(&d.leave(50, GameState::SongSelect)), ["quit"]);
+}
+
+#[test]
+fn loading_is_not_a_pause() {
+    let mut d = Driver::new();
+    d.play(0, "a", 0, 200.0, 0);
+    assert!(d.play(500, "a", 0, 200.0, 0).is_empty());
+    assert!(d.play(500, "a", 0, 200.0, 0).is_empty());
+}
+
+#[test]
+fn other_beatmap_is_a_new_attempt() {
+    let mut d = Driver::new();
+    d.play(0, "a", 0, 200.0, 0);
+    d.play(50, "a", 50, 200.0, 0);
+    assert_eq!(names(&d.play(50, "b", 0, 200.0, 0)), ["retried", "started"]);
+}
+
+#[test]
+fn taiko_starts_with_empty_hp() {
+    let mut d = Driver::with_mode(GameMode::Taiko);
+    assert_eq!(names(&d.play(0, "a", 0, 0.0, 0)), ["started"]);
+    assert!(d.play(50, "a", 50, 0.0, 0).is_empty());
+    assert!(d.play(50, "a", 100, 20.0, 0).is_empty());
+    assert!(d.play(50, "a", 150, 0.0, 0).is_empty());
+    assert_eq!(names(&d.leave(50, GameState::ResultScreen)), ["completed"]);
+}
+
+#[test]
+fn easy_uses_extra_lives_before_failing() {
+    const EZ: u32 = 2;
+    let mut d = Driver::new();
+    d.play_with_mods(0, "a", 0, 200.0, 0, EZ);
+    d.play_with_mods(50, "a", 50, 100.0, 0, EZ);
+    // two extra lives, HP is refilled each time
+    for time in [100, 200] {
+        assert!(d.play_with_mods(50, "a", time, 0.0, 0, EZ).is_empty());
+        assert!(d
+            .play_with_mods(50, "a", time + 50, 200.0, 0, EZ)
+            .is_empty());
+    }
+    assert_eq!(
+        names(&d.play_with_mods(50, "a", 300, 0.0, 0, EZ)),
+        ["failed"]
+    );
+}
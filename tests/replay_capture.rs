@@ -0,0 +1,238 @@
+use rosu_memory_lib::reader::common::{GameMode, GameState, PlayKind};
+use rosu_memory_lib::reader::gameplay::common::{GameplayInfo, GameplayReplayFrame};
+use rosu_memory_lib::reader::resultscreen::common::ResultScreenInfo;
+use rosu_memory_lib::reader::structs::Hit;
+use rosu_memory_lib::replay::capture::{CaptureSnapshot, ReplayCapture, ReplayCaptureEvent};
+use rosu_memory_lib::replay::Replay;
+
+const BEATMAP_MAX_COMBO: u32 = 300;
+
+/// Plays the part of osu!, recording frames like the score does
+struct Driver {
+    capture: ReplayCapture,
+    recorded: Vec<GameplayReplayFrame>,
+    retries: i32,
+    max_combo: i16,
+    misses: i16,
+    beatmap_max_combo: Option<u32>,
+}
+
+impl Driver {
+    fn new() -> Self {
+        Self {
+            capture: ReplayCapture::new(),
+            recorded: Vec::new(),
+            retries: 0,
+            max_combo: 0,
+            misses: 0,
+            beatmap_max_combo: Some(BEATMAP_MAX_COMBO),
+        }
+    }
+
+    fn info(&self, ig_time: i32, hp: f64) -> GameplayInfo {
+        GameplayInfo {
+            score: 1000,
+            mods: 8,
+            combo: self.max_combo,
+            max_combo: self.max_combo,
+            hp,
+            username: "player".to_string(),
+            ig_time,
+            retries: self.retries,
+            hits: Hit {
+                _300: 100,
+                _miss: self.misses,
+                ..Default::default()
+            },
+            kind: PlayKind::Play,
+        }
+    }
+
+    /// Records a frame every 16ms up to `ig_time` then feeds a read
+    fn play(&mut self, ig_time: i32, hp: f64) -> ReplayCaptureEvent {
+        let mut time = self.recorded.last().map_or(0, |f| f.time + 16);
+        while time <= ig_time {
+            self.recorded.push(GameplayReplayFrame {
+                time,
+                x: time as f32,
+                y: 0.0,
+                keys: 0,
+            });
+            time += 16;
+        }
+        let start = self.capture.frame_count().min(self.recorded.len());
+        let snapshot = CaptureSnapshot {
+            beatmap_md5: "a".to_string(),
+            mode: GameMode::Osu,
+            beatmap_max_combo: self.beatmap_max_combo,
+            info: self.info(ig_time, hp),
+            frame_count: self.recorded.len(),
+            new_frames: self.recorded[start..].to_vec(),
+        };
+        self.capture.push(GameState::Playing, Some(snapshot), None)
+    }
+
+    fn retry(&mut self) {
+        self.retries += 1;
+        self.recorded.clear();
+    }
+
+    fn result(&self) -> ResultScreenInfo {
+        ResultScreenInfo {
+            username: "player".to_string(),
+            mode: GameMode::Osu,
+            max_combo: self.max_combo,
+            score: 2000,
+            hits: Hit {
+                _300: 150,
+                _miss: self.misses,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
+
+fn finished(event: ReplayCaptureEvent) -> Replay {
+    match event {
+        ReplayCaptureEvent::Finished(replay) => *replay,
+        other => panic!("expected a finished replay, got {other:?}"),
+    }
+}
+
+#[test]
+fn idle_until_playing() {
+    let mut d = Driver::new();
+    let event = d.capture.push(GameState::SongSelect, None, None);
+    assert!(matches!(event, ReplayCaptureEvent::Idle));
+    let event = d
+        .capture
+        .push(GameState::ResultScreen, None, Some(&d.result()));
+    assert!(matches!(event, ReplayCaptureEvent::Idle));
+}
+
+#[test]
+fn frames_are_read_incrementally() {
+    let mut d = Driver::new();
+    assert!(matches!(
+        d.play(100, 200.0),
+        ReplayCaptureEvent::Capturing { new_frames: 7 }
+    ));
+    assert!(matches!(
+        d.play(200, 200.0),
+        ReplayCaptureEvent::Capturing { new_frames: 6 }
+    ));
+    assert_eq!(d.capture.frame_count(), 13);
+
+    let result = d.result();
+    let replay = finished(d.capture.push(GameState::ResultScreen, None, Some(&result)));
+    assert_eq!(d.capture.frame_count(), 0);
+    assert_eq!(replay.frames.len(), 13);
+    assert_eq!(replay.frame_times()[12], 192);
+    assert!(replay.frames[1..].iter().all(|f| f.time_delta == 16));
+    // headers come from the result screen
+    assert_eq!(replay.score, 2000);
+    assert_eq!(replay.hits._300, 150);
+    assert_eq!(replay.beatmap_md5, "a");
+    assert_eq!(replay.mods, 8);
+    assert!(replay.matches_result(&result));
+}
+
+#[test]
+fn multiplayer_result_screen_gives_the_headers() {
+    let mut d = Driver::new();
+    d.play(100, 200.0);
+    let result = d.result();
+    let replay = finished(d.capture.push(
+        GameState::MultiplayerResultScreenTeamVs,
+        None,
+        Some(&result),
+    ));
+    assert_eq!(replay.score, 2000);
+    assert!(replay.matches_result(&result));
+}
+
+#[test]
+fn perfect_needs_the_beatmap_max_combo() {
+    let mut d = Driver::new();
+    d.max_combo = BEATMAP_MAX_COMBO as i16;
+    d.play(100, 200.0);
+    let result = d.result();
+    let replay = finished(d.capture.push(GameState::ResultScreen, None, Some(&result)));
+    assert!(replay.perfect);
+}
+
+#[test]
+fn slider_break_is_not_perfect() {
+    let mut d = Driver::new();
+    // no miss but the combo broke on a slider end
+    d.max_combo = BEATMAP_MAX_COMBO as i16 - 20;
+    d.play(100, 200.0);
+    let result = d.result();
+    let replay = finished(d.capture.push(GameState::ResultScreen, None, Some(&result)));
+    assert_eq!(replay.hits._miss, 0);
+    assert!(!replay.perfect);
+}
+
+#[test]
+fn unknown_beatmap_max_combo_is_not_perfect() {
+    let mut d = Driver::new();
+    d.beatmap_max_combo = None;
+    d.max_combo = BEATMAP_MAX_COMBO as i16;
+    d.play(100, 200.0);
+    let replay = finished(d.capture.push(GameState::SongSelect, None, None));
+    assert!(!replay.perfect);
+}
+
+#[test]
+fn retry_finishes_the_capture() {
+    let mut d = Driver::new();
+    d.play(100, 200.0);
+    d.play(200, 200.0);
+    d.retry();
+    let replay = finished(d.play(50, 200.0));
+    assert_eq!(replay.frames.len(), 13);
+    // headers come from the last gameplay read
+    assert_eq!(replay.score, 1000);
+
+    assert!(matches!(
+        d.play(50, 200.0),
+        ReplayCaptureEvent::Capturing { new_frames: 4 }
+    ));
+}
+
+#[test]
+fn frame_count_going_back_finishes_the_capture() {
+    let mut d = Driver::new();
+    d.play(200, 200.0);
+    d.recorded.truncate(2);
+    assert_eq!(finished(d.play(0, 200.0)).frames.len(), 13);
+}
+
+#[test]
+fn quit_keeps_the_frames() {
+    let mut d = Driver::new();
+    d.misses = 3;
+    d.play(100, 200.0);
+    let replay = finished(
+        d.capture
+            .push(GameState::SongSelect, None, Some(&d.result())),
+    );
+    assert_eq!(replay.frames.len(), 7);
+    // not on the result screen, the result is ignored
+    assert_eq!(replay.score, 1000);
+    assert_eq!(replay.hits._miss, 3);
+}
+
+#[test]
+fn life_bar_is_sampled_every_two_seconds() {
+    let mut d = Driver::new();
+    d.play(0, 200.0);
+    d.play(1000, 150.0);
+    d.play(2000, 100.0);
+    d.play(3999, 50.0);
+    d.play(4000, 300.0);
+    let replay = finished(d.capture.push(GameState::SongSelect, None, None));
+    let life_bar: Vec<_> = replay.life_bar.iter().map(|p| (p.time, p.life)).collect();
+    assert_eq!(life_bar, [(1000, 0.75), (3999, 0.25)]);
+}
@@ -0,0 +1,135 @@
+use rosu_memory_lib::db::binary::BinaryReader;
+use rosu_memory_lib::reader::common::GameMode;
+use rosu_memory_lib::reader::structs::Hit;
+use rosu_memory_lib::replay::{LifeBarPoint, Replay, ReplayFrame, KEY_K1, KEY_M1};
+
+fn replay(version: i32) -> Replay {
+    Replay {
+        mode: GameMode::Osu,
+        version,
+        beatmap_md5: "0cc175b9c0f1b6a831c399e269772661".to_string(),
+        player: "peppy".to_string(),
+        replay_md5: "6d3c1f9e8d7b4b0a1e2f3a4b5c6d7e8f".to_string(),
+        hits: Hit {
+            _300: 120,
+            _100: 4,
+            _50: 1,
+            _geki: 30,
+            _katu: 2,
+            _miss: 0,
+        },
+        score: 1_000_000,
+        max_combo: 180,
+        perfect: true,
+        mods: 8,
+        life_bar: vec![
+            LifeBarPoint { time: 0, life: 1.0 },
+            LifeBarPoint {
+                time: 2000,
+                life: 0.75,
+            },
+            LifeBarPoint {
+                time: 4010,
+                life: 0.125,
+            },
+        ],
+        timestamp: 638400000000000000,
+        frames: vec![
+            ReplayFrame {
+                time_delta: 0,
+                x: 256.0,
+                y: -500.0,
+                keys: 0,
+            },
+            ReplayFrame {
+                time_delta: 16,
+                x: 100.5,
+                y: 200.25,
+                keys: KEY_M1 | KEY_K1,
+            },
+            ReplayFrame {
+                time_delta: 17,
+                x: 101.0,
+                y: 199.0,
+                keys: 0,
+            },
+        ],
+        rng_seed: Some(42),
+        online_score_id: 4567890123,
+        additional_mod_info: None,
+    }
+}
+
+/// Position and length of the LZMA frame data, after the header
+fn frame_data(bytes: &[u8]) -> (usize, usize) {
+    let mut r = BinaryReader::new(bytes);
+    r.read_u8().unwrap();
+    r.read_i32().unwrap();
+    for _ in 0..3 {
+        r.read_string().unwrap();
+    }
+    // hits, score, max combo, perfect and mods
+    r.skip(6 * 2 + 4 + 2 + 1 + 4).unwrap();
+    r.read_string().unwrap();
+    r.read_i64().unwrap();
+    let len = r.read_i32().unwrap() as usize;
+    (r.position(), len)
+}
+
+fn frame_string(bytes: &[u8]) -> String {
+    let (start, len) = frame_data(bytes);
+    let mut out = Vec::new();
+    lzma_rs::lzma_decompress(&mut &bytes[start..start + len], &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn round_trip() {
+    let original = replay(20250107);
+    let bytes = original.to_bytes().unwrap();
+    let parsed = Replay::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed, original);
+    assert_eq!(parsed.to_bytes().unwrap(), bytes);
+}
+
+#[test]
+fn frames_and_life_bar_format() {
+    let bytes = replay(20250107).to_bytes().unwrap();
+    assert_eq!(
+        frame_string(&bytes),
+        "0|256|-500|0,16|100.5|200.25|5,17|101|199|0,-12345|0|0|42,"
+    );
+
+    let parsed = Replay::from_bytes(&bytes).unwrap();
+    assert_eq!(parsed.frame_times(), [0, 16, 33]);
+    assert_eq!(parsed.life_bar[1].life, 0.75);
+    assert_eq!(parsed.rng_seed, Some(42));
+}
+
+#[test]
+fn old_versions_store_an_int_score_id() {
+    let mut old = replay(20131110);
+    old.online_score_id = 12345;
+    let bytes = old.to_bytes().unwrap();
+    let current = replay(20250107).to_bytes().unwrap();
+    assert_eq!(current.len() - bytes.len(), 4);
+    assert_eq!(Replay::from_bytes(&bytes).unwrap(), old);
+}
+
+#[test]
+fn target_practice_round_trip() {
+    let mut tp = replay(20250107);
+    tp.mods |= 1 << 23;
+    tp.additional_mod_info = Some(0.5);
+    let bytes = tp.to_bytes().unwrap();
+    assert_eq!(Replay::from_bytes(&bytes).unwrap(), tp);
+}
+
+#[test]
+fn invalid_frame_data_errors() {
+    let mut bytes = replay(20250107).to_bytes().unwrap();
+    // corrupt the LZMA properties byte
+    let (start, _) = frame_data(&bytes);
+    bytes[start] = 0xFF;
+    assert!(Replay::from_bytes(&bytes).is_err());
+}
@@ -0,0 +1,111 @@
+use rosu_memory_lib::reader::common::GameMode;
+use rosu_memory_lib::reader::resultscreen::common::ResultScreenPerformance;
+use rosu_memory_lib::reader::structs::Hit;
+
+const MAP: &str = "osu file format v14
+
+[General]
+Mode: 0
+
+[Difficulty]
+HPDrainRate:5
+CircleSize:4
+OverallDifficulty:8
+ApproachRate:9
+SliderMultiplier:1.4
+SliderTickRate:1
+
+[TimingPoints]
+1000,300,4,2,0,50,1,0
+
+[HitObjects]
+64,96,1000,1,0,0:0:0:0:
+112,136,1300,1,0,0:0:0:0:
+160,176,1600,1,0,0:0:0:0:
+208,216,1900,1,0,0:0:0:0:
+256,256,2200,1,0,0:0:0:0:
+304,96,2500,1,0,0:0:0:0:
+352,136,2800,1,0,0:0:0:0:
+400,176,3100,1,0,0:0:0:0:
+64,216,3400,1,0,0:0:0:0:
+112,256,3700,1,0,0:0:0:0:
+160,96,4000,1,0,0:0:0:0:
+208,136,4300,1,0,0:0:0:0:
+256,176,4600,1,0,0:0:0:0:
+304,216,4900,1,0,0:0:0:0:
+352,256,5200,1,0,0:0:0:0:
+400,96,5500,1,0,0:0:0:0:
+64,136,5800,1,0,0:0:0:0:
+112,176,6100,1,0,0:0:0:0:
+160,216,6400,1,0,0:0:0:0:
+208,256,6700,1,0,0:0:0:0:
+256,96,7000,1,0,0:0:0:0:
+304,136,7300,1,0,0:0:0:0:
+352,176,7600,1,0,0:0:0:0:
+400,216,7900,1,0,0:0:0:0:
+64,256,8200,1,0,0:0:0:0:
+112,96,8500,1,0,0:0:0:0:
+160,136,8800,1,0,0:0:0:0:
+208,176,9100,1,0,0:0:0:0:
+256,216,9400,1,0,0:0:0:0:
+304,256,9700,1,0,0:0:0:0:
+352,96,10000,1,0,0:0:0:0:
+400,136,10300,1,0,0:0:0:0:
+64,176,10600,1,0,0:0:0:0:
+112,216,10900,1,0,0:0:0:0:
+160,256,11200,1,0,0:0:0:0:
+208,96,11500,1,0,0:0:0:0:
+256,136,11800,1,0,0:0:0:0:
+304,176,12100,1,0,0:0:0:0:
+352,216,12400,1,0,0:0:0:0:
+400,256,12700,1,0,0:0:0:0:
+";
+
+fn map() -> rosu_pp::Beatmap {
+    rosu_pp::Beatmap::from_bytes(MAP.as_bytes()).unwrap()
+}
+
+fn hits(n300: i16, n100: i16, misses: i16) -> Hit {
+    Hit {
+        _300: n300,
+        _100: n100,
+        _miss: misses,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn full_combo_is_its_own_fc() {
+    let perf = ResultScreenPerformance::new(&map(), GameMode::Osu, &hits(38, 2, 0), 40, 0).unwrap();
+    assert!(perf.pp > 0.0);
+    assert!(perf.stars > 0.0);
+    assert!((perf.pp - perf.fc_pp).abs() < 1e-6);
+}
+
+#[test]
+fn misses_lower_pp_but_not_fc_pp() {
+    let fc = ResultScreenPerformance::new(&map(), GameMode::Osu, &hits(40, 0, 0), 40, 0).unwrap();
+    let missed =
+        ResultScreenPerformance::new(&map(), GameMode::Osu, &hits(38, 0, 2), 20, 0).unwrap();
+    assert!(missed.pp < fc.pp);
+    assert!((missed.fc_pp - fc.pp).abs() < 1e-6);
+}
+
+#[test]
+fn mods_change_stars() {
+    let nomod =
+        ResultScreenPerformance::new(&map(), GameMode::Osu, &hits(40, 0, 0), 40, 0).unwrap();
+    // DT
+    let dt = ResultScreenPerformance::new(&map(), GameMode::Osu, &hits(40, 0, 0), 40, 64).unwrap();
+    assert!(dt.stars > nomod.stars);
+}
+
+#[test]
+fn converts_and_unknown_mode() {
+    let taiko =
+        ResultScreenPerformance::new(&map(), GameMode::Taiko, &hits(40, 0, 0), 40, 0).unwrap();
+    assert!(taiko.pp > 0.0);
+    assert!(
+        ResultScreenPerformance::new(&map(), GameMode::Unknown, &hits(40, 0, 0), 40, 0).is_err()
+    );
+}
@@ -0,0 +1,66 @@
+use rosu_memory_lib::db::scores::{ScoresDb, MOD_TARGET_PRACTICE};
+use rosu_memory_lib::reader::common::GameMode;
+
+const FIXTURE: &[u8] = include_bytes!("fixtures/scores.db");
+const MAP_A: &str = "0cc175b9c0f1b6a831c399e269772661";
+const MAP_B: &str = "92eb5ffee6ae2fec3ad71c777531578f";
+
+#[test]
+fn parse_fixture() {
+    let db = ScoresDb::from_bytes(FIXTURE).unwrap();
+    assert_eq!(db.version, 20250107);
+    assert_eq!(db.beatmaps.len(), 2);
+
+    let scores = db.scores(MAP_A);
+    assert_eq!(scores.len(), 2);
+    let current = &scores[0];
+    assert_eq!(current.mode, GameMode::Osu);
+    assert_eq!(current.player, "peppy");
+    assert_eq!(current.hits._300, 500);
+    assert_eq!(current.hits._miss, 1);
+    assert_eq!(current.score, 1234567);
+    assert_eq!(current.max_combo, 700);
+    assert!(!current.perfect);
+    assert_eq!(current.mods, 72);
+    assert_eq!(current.timestamp, 638400000000000000);
+    assert_eq!(current.online_score_id, 4567890123);
+    assert_eq!(current.additional_mod_info, None);
+}
+
+#[test]
+fn old_scores_have_an_int_online_id() {
+    let db = ScoresDb::from_bytes(FIXTURE).unwrap();
+    let old = &db.scores(MAP_A)[1];
+    assert_eq!(old.version, 20131110);
+    assert_eq!(old.player, "old player");
+    assert_eq!(old.online_score_id, 12345);
+    assert!(old.perfect);
+    assert_eq!(old.timestamp, 635200000000000000);
+
+    // the next beatmap is only right if the int was read with the right size
+    let scores = db.scores(MAP_B);
+    assert_eq!(scores.len(), 1);
+    assert_eq!(scores[0].score, 300000);
+}
+
+#[test]
+fn target_practice_extra_double() {
+    let db = ScoresDb::from_bytes(FIXTURE).unwrap();
+    let score = &db.scores(MAP_B)[0];
+    assert_eq!(score.mods, MOD_TARGET_PRACTICE);
+    assert_eq!(score.additional_mod_info, Some(0.5));
+}
+
+#[test]
+fn best_score() {
+    let db = ScoresDb::from_bytes(FIXTURE).unwrap();
+    assert_eq!(db.best(MAP_A, None).unwrap().score, 1234567);
+    assert_eq!(db.best(MAP_A, Some("old player")).unwrap().score, 987654);
+    assert!(db.best(MAP_A, Some("nobody")).is_none());
+    assert!(db.scores("unknown").is_empty());
+}
+
+#[test]
+fn truncated_file_errors() {
+    assert!(ScoresDb::from_bytes(&FIXTURE[..FIXTURE.len() - 4]).is_err());
+}
@@ -0,0 +1,238 @@
+use rosu_memory_lib::reader::common::GameMode;
+use rosu_memory_lib::reader::structs::Hit;
+use rosu_memory_lib::scoring::{calculate_accuracy, calculate_projected_score, Judgements};
+
+fn hit(geki: i16, n300: i16, katu: i16, n100: i16, n50: i16, misses: i16) -> Hit {
+    Hit {
+        _geki: geki,
+        _300: n300,
+        _katu: katu,
+        _100: n100,
+        _50: n50,
+        _miss: misses,
+    }
+}
+
+fn accuracy(mode: GameMode, hit: Hit) -> f64 {
+    calculate_accuracy(&mode, &hit).unwrap()
+}
+
+fn assert_accuracy(mode: GameMode, hit: Hit, expected: f64) {
+    let accuracy = accuracy(mode, hit);
+    assert!(
+        (accuracy - expected).abs() < 1e-9,
+        "{mode:?}: expected {expected}, got {accuracy}"
+    );
+}
+
+#[test]
+fn empty_play_is_full_accuracy() {
+    for mode in [
+        GameMode::Osu,
+        GameMode::Taiko,
+        GameMode::Catch,
+        GameMode::Mania,
+    ] {
+        assert_accuracy(mode, Hit::default(), 100.0);
+    }
+}
+
+#[test]
+fn unknown_mode_is_an_error() {
+    assert!(calculate_accuracy(&GameMode::Unknown, &hit(0, 1, 0, 0, 0, 0)).is_err());
+    assert!(Judgements::from_hits(&GameMode::Unknown, &Hit::default()).is_err());
+}
+
+#[test]
+fn osu_accuracy() {
+    assert_accuracy(GameMode::Osu, hit(0, 100, 0, 0, 0, 0), 100.0);
+    assert_accuracy(GameMode::Osu, hit(0, 99, 0, 1, 0, 0), 99.0 + 1.0 / 3.0);
+    assert_accuracy(GameMode::Osu, hit(0, 99, 0, 0, 1, 0), 99.0 + 1.0 / 6.0);
+    assert_accuracy(GameMode::Osu, hit(0, 99, 0, 0, 0, 1), 99.0);
+    assert_accuracy(
+        GameMode::Osu,
+        hit(0, 1000, 0, 10, 1, 2),
+        6021.0 / 6078.0 * 100.0,
+    );
+    assert_accuracy(GameMode::Osu, hit(0, 0, 0, 0, 0, 5), 0.0);
+    // geki/katu are part of 300/100 and must not change anything
+    assert_accuracy(
+        GameMode::Osu,
+        hit(40, 1000, 5, 10, 1, 2),
+        6021.0 / 6078.0 * 100.0,
+    );
+}
+
+#[test]
+fn taiko_accuracy() {
+    assert_accuracy(GameMode::Taiko, hit(0, 100, 0, 0, 0, 0), 100.0);
+    // a GOOD is worth half a GREAT
+    assert_accuracy(GameMode::Taiko, hit(0, 99, 0, 1, 0, 0), 99.5);
+    assert_accuracy(GameMode::Taiko, hit(0, 99, 0, 0, 0, 1), 99.0);
+    assert_accuracy(
+        GameMode::Taiko,
+        hit(0, 1200, 0, 30, 0, 5),
+        2430.0 / 2470.0 * 100.0,
+    );
+}
+
+#[test]
+fn catch_accuracy() {
+    // fruits, droplets and tiny droplets are all worth the same
+    assert_accuracy(GameMode::Catch, hit(0, 10, 0, 10, 80, 0), 100.0);
+    assert_accuracy(GameMode::Catch, hit(0, 99, 0, 0, 0, 1), 99.0);
+    // missed tiny droplets (katu) lower the accuracy
+    assert_accuracy(GameMode::Catch, hit(0, 50, 1, 0, 49, 0), 99.0);
+    assert_accuracy(
+        GameMode::Catch,
+        hit(0, 800, 20, 100, 500, 3),
+        1400.0 / 1423.0 * 100.0,
+    );
+}
+
+#[test]
+fn mania_accuracy() {
+    // MAX and 300 are both worth 300 on stable
+    assert_accuracy(GameMode::Mania, hit(50, 50, 0, 0, 0, 0), 100.0);
+    assert_accuracy(
+        GameMode::Mania,
+        hit(0, 2, 1, 0, 0, 0),
+        800.0 / 900.0 * 100.0,
+    );
+    assert_accuracy(
+        GameMode::Mania,
+        hit(0, 5, 0, 1, 0, 0),
+        1600.0 / 1800.0 * 100.0,
+    );
+    assert_accuracy(
+        GameMode::Mania,
+        hit(0, 5, 0, 0, 1, 0),
+        1550.0 / 1800.0 * 100.0,
+    );
+    assert_accuracy(
+        GameMode::Mania,
+        hit(0, 5, 0, 0, 0, 1),
+        1500.0 / 1800.0 * 100.0,
+    );
+    assert_accuracy(
+        GameMode::Mania,
+        hit(2000, 500, 50, 20, 5, 10),
+        762250.0 / 775500.0 * 100.0,
+    );
+}
+
+#[test]
+fn long_maps_do_not_overflow() {
+    // osu! stores the counts as unsigned 16 bits, above i16::MAX they read negative
+    let n300 = 40000u16 as i16;
+    assert!(n300 < 0);
+    assert_accuracy(GameMode::Osu, hit(0, n300, 0, 0, 0, 0), 100.0);
+    assert_accuracy(
+        GameMode::Osu,
+        hit(0, n300, 0, 5000, 0, 0),
+        (40000.0 * 300.0 + 5000.0 * 100.0) / (45000.0 * 300.0) * 100.0,
+    );
+    // the sum of the counts alone is above i16::MAX
+    assert_accuracy(GameMode::Mania, hit(30000, 30000, 0, 0, 0, 0), 100.0);
+}
+
+#[test]
+fn judgements_are_named_per_mode() {
+    let counts = hit(1, 2, 3, 4, 5, 6);
+    assert_eq!(
+        Judgements::from_hits(&GameMode::Osu, &counts).unwrap(),
+        Judgements::Osu {
+            n300: 2,
+            n100: 4,
+            n50: 5,
+            misses: 6
+        }
+    );
+    assert_eq!(
+        Judgements::from_hits(&GameMode::Taiko, &counts).unwrap(),
+        Judgements::Taiko {
+            great: 2,
+            ok: 4,
+            misses: 6
+        }
+    );
+    assert_eq!(
+        Judgements::from_hits(&GameMode::Catch, &counts).unwrap(),
+        Judgements::Catch {
+            fruits: 2,
+            droplets: 4,
+            tiny_droplets: 5,
+            tiny_droplet_misses: 3,
+            misses: 6
+        }
+    );
+    assert_eq!(
+        Judgements::from_hits(&GameMode::Mania, &counts).unwrap(),
+        Judgements::Mania {
+            max: 1,
+            n300: 2,
+            n200: 3,
+            n100: 4,
+            n50: 5,
+            misses: 6
+        }
+    );
+}
+
+#[test]
+fn judged_objects_skip_tiny_droplets() {
+    let catch = Judgements::from_hits(&GameMode::Catch, &hit(0, 10, 3, 5, 40, 2)).unwrap();
+    assert_eq!(catch.total(), 60);
+    assert_eq!(catch.judged_objects(), 17);
+    assert_eq!(catch.mode(), GameMode::Catch);
+
+    let osu = Judgements::from_hits(&GameMode::Osu, &hit(3, 10, 1, 5, 1, 2)).unwrap();
+    assert_eq!(osu.total(), 18);
+    assert_eq!(osu.judged_objects(), 18);
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_helper_keeps_its_results() {
+    use rosu_memory_lib::reader::helpers::calculate_accuracy as old_accuracy;
+
+    assert_eq!(old_accuracy(&GameMode::Osu, &Hit::default()).unwrap(), 0.0);
+    assert_eq!(
+        old_accuracy(&GameMode::Unknown, &hit(0, 10, 0, 0, 0, 0)).unwrap(),
+        0.0
+    );
+    let play = hit(0, 90, 0, 10, 0, 0);
+    assert_eq!(
+        old_accuracy(&GameMode::Osu, &play).unwrap(),
+        accuracy(GameMode::Osu, play)
+    );
+}
+
+/// Half the combo and half the accuracy, on a beatmap of 2 objects and 2 combo
+fn half_projected_score(mode: GameMode, hit: Hit) -> f64 {
+    calculate_projected_score(&mode, &hit, 1, 2, 2, 0).unwrap()
+}
+
+#[test]
+fn osu_projected_score() {
+    let score = half_projected_score(GameMode::Osu, hit(0, 1, 0, 0, 0, 1));
+    assert!((score - (350_000.0 + 300_000.0 * 0.5f64.powi(10))).abs() < 1e-6);
+}
+
+#[test]
+fn taiko_projected_score() {
+    let score = half_projected_score(GameMode::Taiko, hit(0, 1, 0, 0, 0, 1));
+    assert!((score - (125_000.0 + 750_000.0 * 0.5f64.powf(3.6))).abs() < 1e-6);
+}
+
+#[test]
+fn catch_projected_score() {
+    let score = half_projected_score(GameMode::Catch, hit(0, 1, 0, 0, 0, 1));
+    assert!((score - (300_000.0 + 400_000.0 * 0.5f64.powi(5))).abs() < 1e-6);
+}
+
+#[test]
+fn mania_projected_score() {
+    let score = half_projected_score(GameMode::Mania, hit(1, 0, 0, 0, 0, 1));
+    assert!((score - (75_000.0 + 850_000.0 * 0.5f64.powi(3))).abs() < 1e-6);
+}
@@ -0,0 +1,155 @@
+use std::time::Duration;
+
+use rosu_memory_lib::reader::common::{GameMode, PlayKind};
+use rosu_memory_lib::reader::gameplay::common::GameplayInfo;
+use rosu_memory_lib::reader::gameplay::session::{PlayEvent, PlayResult};
+use rosu_memory_lib::reader::user::common::UserInfo;
+use rosu_memory_lib::stats::{PlayOutcome, SessionStats};
+
+fn result(md5: &str, score: i32, play_time: u64) -> Box<PlayResult> {
+    watched(md5, score, play_time, PlayKind::Play)
+}
+
+fn watched(md5: &str, score: i32, play_time: u64, kind: PlayKind) -> Box<PlayResult> {
+    Box::new(PlayResult {
+        beatmap_md5: md5.to_string(),
+        mode: GameMode::Osu,
+        info: GameplayInfo {
+            score,
+            mods: 8,
+            combo: 10,
+            max_combo: 120,
+            hp: 150.0,
+            username: "player".to_string(),
+            ig_time: 0,
+            retries: 0,
+            hits: Default::default(),
+            kind,
+        },
+        play_time: Duration::from_millis(play_time),
+    })
+}
+
+fn user(pp: i32, rank: i32, ranked_score: i64) -> UserInfo {
+    UserInfo {
+        id: 1,
+        username: "player".to_string(),
+        pp,
+        rankedscore: ranked_score,
+        level: 100.0,
+        playcount: 1000,
+        rank,
+        playmode: 0,
+        accuracy: 98.5,
+        country_code: 0,
+        bancho_status: 0,
+    }
+}
+
+fn session() -> SessionStats {
+    let mut stats = SessionStats::new();
+    stats.record_user(&user(5000, 10000, 1_000_000));
+    stats.record(&PlayEvent::Started {
+        beatmap_md5: "a".to_string(),
+        mode: GameMode::Osu,
+    });
+    stats.record(&PlayEvent::Retried(result("a", 100, 5_000)));
+    stats.record(&PlayEvent::Retried(result("a", 200, 10_000)));
+    stats.record(&PlayEvent::Failed(result("a", 300, 20_000)));
+    stats.record(&PlayEvent::Completed(result("a", 1000, 60_000)));
+    stats.record(&PlayEvent::Quit(result("b", 50, 1_000)));
+    stats.record_user(&user(5020, 9900, 1_001_000));
+    stats
+}
+
+#[test]
+fn counts_outcomes_and_time() {
+    let stats = session();
+    assert_eq!(stats.plays.len(), 5);
+    assert_eq!(stats.passes(), 1);
+    assert_eq!(stats.fails(), 1);
+    assert_eq!(stats.retries(), 2);
+    assert_eq!(stats.quits(), 1);
+    assert_eq!(stats.time_played(), Duration::from_millis(96_000));
+    assert_eq!(stats.plays[3].outcome, PlayOutcome::Passed);
+    assert_eq!(stats.plays[3].score, 1000);
+}
+
+#[test]
+fn ignores_plays_of_others() {
+    let mut stats = session();
+    for kind in [PlayKind::Replay, PlayKind::Spectating, PlayKind::Autoplay] {
+        stats.record(&PlayEvent::Completed(watched("c", 1000, 60_000, kind)));
+    }
+    assert_eq!(stats.plays.len(), 5);
+    assert_eq!(stats.beatmap("c").plays, 0);
+}
+
+#[test]
+fn play_kind_priority() {
+    const AUTOPLAY: u32 = 1 << 11;
+    const CINEMA: u32 = 1 << 22;
+    assert_eq!(PlayKind::new(0, false, false), PlayKind::Play);
+    assert_eq!(PlayKind::new(0, false, true), PlayKind::Replay);
+    assert_eq!(PlayKind::new(0, true, true), PlayKind::Spectating);
+    assert_eq!(PlayKind::new(AUTOPLAY, false, true), PlayKind::Autoplay);
+    assert_eq!(PlayKind::new(CINEMA | 8, true, false), PlayKind::Autoplay);
+    assert!(PlayKind::new(8, false, false).is_own_play());
+}
+
+#[test]
+fn user_gains_since_start() {
+    let stats = session();
+    assert_eq!(stats.pp_gain(), Some(20));
+    assert_eq!(stats.rank_gain(), Some(100));
+    assert_eq!(stats.ranked_score_gain(), Some(1000));
+    assert!(SessionStats::new().pp_gain().is_none());
+}
+
+#[test]
+fn per_beatmap_counts() {
+    let stats = session();
+    let a = stats.beatmap("a");
+    assert_eq!(a.plays, 4);
+    assert_eq!(a.retries, 2);
+    assert_eq!(a.fails, 1);
+    assert_eq!(a.passes, 1);
+    assert_eq!(stats.beatmap("b").quits, 1);
+    assert_eq!(stats.beatmap("c").plays, 0);
+    assert_eq!(stats.beatmaps().len(), 2);
+}
+
+#[test]
+fn round_trip() {
+    let stats = session();
+    let read = SessionStats::from_bytes(&stats.to_bytes()).unwrap();
+    assert_eq!(read, stats);
+}
+
+#[test]
+fn rejects_other_versions() {
+    let mut bytes = session().to_bytes();
+    bytes[0] = 0xFF;
+    assert!(SessionStats::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn resumes_recent_session_from_disk() {
+    let path = std::env::temp_dir().join(format!("session-stats-{}.bin", std::process::id()));
+    let stats = session();
+    stats.write_to_path(&path).unwrap();
+
+    let resumed = SessionStats::load_or_new(&path, Duration::from_secs(60));
+    assert_eq!(resumed, stats);
+
+    let mut old = stats.clone();
+    old.updated_at -= 120_000;
+    old.write_to_path(&path).unwrap();
+    let expired = SessionStats::load_or_new(&path, Duration::from_secs(60));
+    assert!(expired.plays.is_empty());
+
+    std::fs::remove_file(&path).unwrap();
+    assert!(SessionStats::load_or_new(&path, Duration::from_secs(60))
+        .plays
+        .is_empty());
+}
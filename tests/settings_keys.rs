@@ -0,0 +1,28 @@
+use rosu_memory_lib::reader::settings::common::OsuKey;
+
+#[test]
+fn key_names_round_trip() {
+    for (code, name) in [
+        (90, "Z"),
+        (88, "X"),
+        (49, "D1"),
+        (100, "NumPad4"),
+        (112, "F1"),
+        (160, "LeftShift"),
+        (32, "Space"),
+        (188, "OemComma"),
+    ] {
+        assert_eq!(OsuKey(code).name().as_deref(), Some(name));
+        assert_eq!(OsuKey::from_name(name), Some(OsuKey(code)));
+    }
+}
+
+#[test]
+fn unknown_keys() {
+    assert_eq!(OsuKey(255).name(), None);
+    assert_eq!(OsuKey(255).to_string(), "Key(255)");
+    assert_eq!(OsuKey::from_name("D10"), None);
+    assert_eq!(OsuKey::from_name("F0"), None);
+    assert_eq!(OsuKey::from_name("NotAKey"), None);
+    assert_eq!(OsuKey::from_name("leftshift"), Some(OsuKey(160)));
+}
@@ -0,0 +1,75 @@
+use rosu_memory_lib::reader::skin::common::{SkinColour, SkinIni};
+
+const SKIN_INI: &str = "\u{feff}// my skin
+[General]
+Name: Cool Skin
+Author: someone
+Version: 2.7
+
+[Colours]
+Combo2: 0,128,255
+Combo1: 255,0,0 // red
+SliderBorder: 255,255,255,128
+MenuGlow: not a colour
+Combo9: 1,2,3
+
+[Mania]
+Keys: 4
+ColumnWidth: 40,40,40,40
+Colour1: 10,20,30
+
+[Mania]
+Keys: 7
+HitPosition: 420
+";
+
+#[test]
+fn parses_general_and_colours() {
+    let ini = SkinIni::parse(SKIN_INI);
+    assert_eq!(ini.name, "Cool Skin");
+    assert_eq!(ini.author, "someone");
+    assert_eq!(ini.version, "2.7");
+    assert_eq!(
+        ini.combo_colours,
+        [
+            SkinColour {
+                r: 255,
+                g: 0,
+                b: 0,
+                a: 255
+            },
+            SkinColour {
+                r: 0,
+                g: 128,
+                b: 255,
+                a: 255
+            }
+        ]
+    );
+    assert_eq!(ini.colour("SliderBorder").unwrap().a, 128);
+    assert!(ini.colour("MenuGlow").is_none());
+    // osu! only has 8 combo colours, others are kept as plain colours
+    assert_eq!(ini.colour("Combo9").unwrap().to_hex(), "#010203");
+}
+
+#[test]
+fn parses_mania_sections() {
+    let ini = SkinIni::parse(SKIN_INI);
+    assert_eq!(ini.mania.len(), 2);
+    let four = ini.mania_config(4).unwrap();
+    assert_eq!(four.value("ColumnWidth"), Some("40,40,40,40"));
+    assert_eq!(four.colour("Colour1").unwrap().to_hex(), "#0a141e");
+    assert_eq!(
+        ini.mania_config(7).unwrap().value("HitPosition"),
+        Some("420")
+    );
+    assert!(ini.mania_config(5).is_none());
+}
+
+#[test]
+fn defaults_without_general() {
+    let ini = SkinIni::parse("");
+    assert_eq!(ini.version, "1.0");
+    assert!(ini.name.is_empty());
+    assert!(ini.combo_colours.is_empty());
+}
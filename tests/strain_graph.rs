@@ -0,0 +1,104 @@
+use rosu_map::Beatmap as RmBeatmap;
+use rosu_memory_lib::reader::beatmap::common::{BeatmapDifficultySettings, BeatmapSection};
+use rosu_memory_lib::reader::beatmap::stable::file::strain_graph_from_beatmap;
+
+/// Objects every 500ms from 1s to 5s and from 10s to 20s, a break between
+/// them, a kiai from 12s to 15s and another one from 18s to the end
+const BEATMAP: &str = "osu file format v14
+
+[General]
+Mode: 0
+
+[Difficulty]
+HPDrainRate:5
+CircleSize:4
+OverallDifficulty:8
+ApproachRate:9
+SliderMultiplier:1.4
+SliderTickRate:1
+
+[Events]
+2,5000,10000
+
+[TimingPoints]
+1000,500,4,2,0,100,1,0
+12000,-100,4,2,0,100,0,1
+15000,-100,4,2,0,100,0,0
+18000,-100,4,2,0,100,0,1
+
+[HitObjects]
+64,192,1000,1,0,0:0:0:0:
+448,192,1500,1,0,0:0:0:0:
+64,192,2000,1,0,0:0:0:0:
+448,192,2500,1,0,0:0:0:0:
+64,192,3000,1,0,0:0:0:0:
+448,192,3500,1,0,0:0:0:0:
+64,192,4000,1,0,0:0:0:0:
+448,192,4500,1,0,0:0:0:0:
+64,192,5000,1,0,0:0:0:0:
+64,192,10000,1,0,0:0:0:0:
+448,192,10500,1,0,0:0:0:0:
+64,192,11000,1,0,0:0:0:0:
+448,192,11500,1,0,0:0:0:0:
+64,192,12000,1,0,0:0:0:0:
+448,192,12500,1,0,0:0:0:0:
+64,192,13000,1,0,0:0:0:0:
+448,192,13500,1,0,0:0:0:0:
+64,192,14000,1,0,0:0:0:0:
+448,192,14500,1,0,0:0:0:0:
+64,192,15000,1,0,0:0:0:0:
+448,192,15500,1,0,0:0:0:0:
+64,192,16000,1,0,0:0:0:0:
+448,192,16500,1,0,0:0:0:0:
+64,192,17000,1,0,0:0:0:0:
+448,192,17500,1,0,0:0:0:0:
+64,192,18000,1,0,0:0:0:0:
+448,192,18500,1,0,0:0:0:0:
+64,192,19000,1,0,0:0:0:0:
+448,192,19500,1,0,0:0:0:0:
+64,192,20000,1,0,0:0:0:0:
+";
+
+#[test]
+fn sections_and_points() {
+    let map = RmBeatmap::from_bytes(BEATMAP.as_bytes()).unwrap();
+    let pp_map = rosu_pp::Beatmap::from_bytes(BEATMAP.as_bytes()).unwrap();
+    let graph = strain_graph_from_beatmap(&map, &pp_map, &BeatmapDifficultySettings::default(), 38)
+        .unwrap();
+
+    assert_eq!(graph.start_time, 1000.0);
+    assert_eq!(graph.end_time, 20000.0);
+    assert_eq!(graph.strains.len(), 38);
+    assert_eq!(graph.point_duration(), 500.0);
+    // normalized to the hardest point
+    assert!(graph.strains.iter().all(|s| (0.0..=1.0).contains(s)));
+    assert!(graph.strains.contains(&1.0));
+
+    // nothing to play during the break
+    let strain_at = |time| graph.strains[graph.point_at(time).unwrap()];
+    assert!(strain_at(7500.0) < strain_at(15000.0));
+
+    let bounds = |sections: &[BeatmapSection]| -> Vec<(f64, f64)> {
+        sections
+            .iter()
+            .map(|s| (s.start_time, s.end_time))
+            .collect()
+    };
+    assert_eq!(bounds(&graph.breaks), [(5000.0, 10000.0)]);
+    // the last kiai lasts until the end of the beatmap
+    assert_eq!(
+        bounds(&graph.kiai),
+        [(12000.0, 15000.0), (18000.0, 20000.0)]
+    );
+}
+
+#[test]
+fn empty_beatmap_is_an_error() {
+    let beatmap = BEATMAP.split("[HitObjects]").next().unwrap();
+    let map = RmBeatmap::from_bytes(beatmap.as_bytes()).unwrap();
+    let pp_map = rosu_pp::Beatmap::from_bytes(beatmap.as_bytes()).unwrap();
+    assert!(
+        strain_graph_from_beatmap(&map, &pp_map, &BeatmapDifficultySettings::default(), 38)
+            .is_err()
+    );
+}
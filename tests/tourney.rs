@@ -0,0 +1,73 @@
+use rosu_memory_lib::reader::tourney::clients::{spectate_client_slot, Tourney, TourneySide};
+use rosu_memory_lib::reader::tourney::common::{TourneyIpcState, TourneyManagerInfo, TourneyTeam};
+
+#[test]
+fn team_size_and_side() {
+    assert_eq!(Tourney::team_size_of(0), 0);
+    assert_eq!(Tourney::team_size_of(2), 1);
+    assert_eq!(Tourney::team_size_of(8), 4);
+    // the left team gets the extra client
+    assert_eq!(Tourney::team_size_of(3), 2);
+
+    let sides: Vec<_> = (0..4).map(|slot| TourneySide::from_slot(slot, 2)).collect();
+    assert_eq!(
+        sides,
+        [
+            TourneySide::Left,
+            TourneySide::Left,
+            TourneySide::Right,
+            TourneySide::Right
+        ]
+    );
+}
+
+#[test]
+fn slot_from_command_line() {
+    assert_eq!(
+        spectate_client_slot(&["C:\\osu!\\osu!.exe", "-spectateclient", "3"]),
+        Some(3)
+    );
+    assert_eq!(
+        spectate_client_slot(&["osu!.exe", "-devserver", "x", "-SpectateClient", "0"]),
+        Some(0)
+    );
+    assert_eq!(spectate_client_slot(&["osu!.exe"]), None);
+    assert_eq!(spectate_client_slot(&["osu!.exe", "-spectateclient"]), None);
+    assert_eq!(
+        spectate_client_slot(&["osu!.exe", "-spectateclient", "left"]),
+        None
+    );
+}
+
+#[test]
+fn manager_info() {
+    let team = |score| TourneyTeam {
+        score,
+        ..Default::default()
+    };
+    let info = TourneyManagerInfo {
+        best_of: 9,
+        left: team(500_000),
+        right: team(750_000),
+        ..Default::default()
+    };
+    assert_eq!(info.first_to(), 5);
+    assert_eq!(info.score_diff(), -250_000);
+
+    let bo8 = TourneyManagerInfo {
+        best_of: 8,
+        ..Default::default()
+    };
+    assert_eq!(bo8.first_to(), 5);
+}
+
+#[test]
+fn ipc_state_from_memory() {
+    assert_eq!(TourneyIpcState::from(0), TourneyIpcState::Initialising);
+    assert_eq!(TourneyIpcState::from(1), TourneyIpcState::Idle);
+    assert_eq!(TourneyIpcState::from(2), TourneyIpcState::WaitingForClients);
+    assert_eq!(TourneyIpcState::from(3), TourneyIpcState::Playing);
+    assert_eq!(TourneyIpcState::from(4), TourneyIpcState::Ranking);
+    assert_eq!(TourneyIpcState::from(5), TourneyIpcState::Unknown);
+    assert_eq!(TourneyIpcState::from(-1), TourneyIpcState::Unknown);
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use rosu_memory_lib::reader::wine::{is_wine, WineKind, WinePrefix};
+
+fn env(vars: &[(&str, &str)]) -> HashMap<String, String> {
+    vars.iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn prefix_from_env() {
+    let wine = WinePrefix::from_env(&env(&[("HOME", "/home/me"), ("WINEPREFIX", "/games/osu")]));
+    assert_eq!(wine, Some(WinePrefix::new(WineKind::Wine, "/games/osu")));
+
+    let default = WinePrefix::from_env(&env(&[("HOME", "/home/me")]));
+    assert_eq!(
+        default,
+        Some(WinePrefix::new(WineKind::Wine, "/home/me/.wine"))
+    );
+
+    let proton = WinePrefix::from_env(&env(&[("STEAM_COMPAT_DATA_PATH", "/steam/compatdata/1")]));
+    assert_eq!(
+        proton,
+        Some(WinePrefix::new(WineKind::Proton, "/steam/compatdata/1/pfx"))
+    );
+
+    let umu = WinePrefix::from_env(&env(&[
+        ("UMU_ID", "umu-osu"),
+        ("WINEPREFIX", "/home/me/Games/umu/osu"),
+    ]));
+    assert_eq!(
+        umu,
+        Some(WinePrefix::new(WineKind::Umu, "/home/me/Games/umu/osu"))
+    );
+
+    assert_eq!(WinePrefix::from_env(&env(&[])), None);
+}
+
+#[test]
+fn wine_processes() {
+    let home = env(&[("HOME", "/home/me")]);
+    assert!(is_wine(Some(Path::new("/usr/bin/wine64-preloader")), &home));
+    assert!(is_wine(Some(Path::new("/opt/wine/bin/wine")), &home));
+    assert!(is_wine(None, &env(&[("WINEPREFIX", "/games/osu")])));
+    assert!(is_wine(None, &env(&[("STEAM_COMPAT_DATA_PATH", "/s")])));
+    assert!(is_wine(None, &env(&[("UMU_ID", "umu-osu")])));
+
+    // HOME alone gives a prefix but isn't wine
+    assert!(!is_wine(Some(Path::new("/usr/bin/osu")), &home));
+    assert!(!is_wine(None, &home));
+}
+
+#[test]
+fn translate_default_drives() {
+    let prefix = WinePrefix::new(WineKind::Wine, "/nonexistent/prefix");
+    let osu_dir = Path::new("/nonexistent/prefix/drive_c/osu!");
+
+    assert_eq!(
+        prefix.translate(r"C:\osu!\Songs", None),
+        PathBuf::from("/nonexistent/prefix/drive_c/osu!/Songs")
+    );
+    assert_eq!(
+        prefix.translate(r"Z:\home\me\osu!\Songs", None),
+        PathBuf::from("/home/me/osu!/Songs")
+    );
+    assert_eq!(
+        prefix.translate("Songs", Some(osu_dir)),
+        osu_dir.join("Songs")
+    );
+    assert_eq!(
+        prefix.translate("/mnt/songs", Some(osu_dir)),
+        PathBuf::from("/mnt/songs")
+    );
+    // not a drive, only a folder with a colon
+    assert_eq!(
+        prefix.translate("ab:c", Some(osu_dir)),
+        osu_dir.join("ab:c")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn translate_with_dosdevices() {
+    let root = std::env::temp_dir().join(format!("wine-prefix-{}", std::process::id()));
+    let songs = root.join("songs-disk");
+    std::fs::create_dir_all(root.join("prefix/dosdevices")).unwrap();
+    std::fs::create_dir_all(root.join("prefix/drive_c")).unwrap();
+    std::fs::create_dir_all(&songs).unwrap();
+    std::os::unix::fs::symlink("../drive_c", root.join("prefix/dosdevices/c:")).unwrap();
+    std::os::unix::fs::symlink(&songs, root.join("prefix/dosdevices/d:")).unwrap();
+
+    let prefix = WinePrefix::new(WineKind::Wine, root.join("prefix"));
+    let c = prefix.translate(r"C:\osu!", None);
+    let d = prefix.translate(r"d:\Songs", None);
+    let root = std::fs::canonicalize(&root).unwrap();
+    std::fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(c, root.join("prefix/drive_c/osu!"));
+    assert_eq!(d, root.join("songs-disk/Songs"));
+}